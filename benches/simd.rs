@@ -0,0 +1,122 @@
+//! Times the `f32` products that run on the SIMD kernels against the same products through the
+//! generic code. `DualN<f32, 0>` carries no derivatives, so it does exactly the `f32` arithmetic
+//! of the generic code without being dispatched to the kernels.
+//!
+//! `cargo bench --bench simd --features simd`
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use linear::{DualN, Mat4, Quaternion, Vec3, Vec4};
+
+type Generic = DualN<f32, 0>;
+
+const ITERATIONS: u32 = 2_000_000;
+
+//the best of a few runs, in nanoseconds per call
+fn time(mut f: impl FnMut()) -> f64 {
+    let mut best = Duration::MAX;
+    for _ in 0..5 {
+        let start = Instant::now();
+        for _ in 0..ITERATIONS {
+            f();
+        }
+        best = best.min(start.elapsed());
+    }
+    best.as_nanos() as f64 / ITERATIONS as f64
+}
+
+fn report(name: &str, simd: f64, generic: f64) {
+    println!(
+        "{:<24} simd {:>6.2} ns   generic {:>6.2} ns   speedup {:.2}x",
+        name,
+        simd,
+        generic,
+        generic / simd
+    );
+}
+
+fn main() {
+    let m =
+        Mat4::rotation(0.3, Vec3::new(0.0, 0.6, 0.8)) * Mat4::translation(Vec3::new(1.0, 2.0, 3.0));
+    let n = Mat4::scale(1.5_f32) * m;
+    let v = Vec4::new(0.5, -1.0, 2.0, 1.0_f32);
+
+    let g = Generic::constant;
+    let (gm, gn) = (m.map(g), n.map(g));
+    let gv = Vec4::new(g(v.x), g(v.y), g(v.z), g(v.w));
+
+    report(
+        "Mat4 * Mat4",
+        time(|| {
+            black_box(black_box(m) * black_box(n));
+        }),
+        time(|| {
+            black_box(black_box(gm) * black_box(gn));
+        }),
+    );
+    report(
+        "Mat4 * Vec4",
+        time(|| {
+            black_box(black_box(m) * black_box(v));
+        }),
+        time(|| {
+            black_box(black_box(gm) * black_box(gv));
+        }),
+    );
+    report(
+        "Mat4::inverse",
+        time(|| {
+            black_box(black_box(m).inverse());
+        }),
+        time(|| {
+            black_box(black_box(gm).inverse());
+        }),
+    );
+
+    let (a, b) = (v.truncate(), Vec3::new(-2.0, 0.25, 1.5_f32));
+    let (ga, gb) = (a.map(g), b.map(g));
+    let gw = Vec4::new(g(v.w), g(v.z), g(v.y), g(v.x));
+    let w = Vec4::new(v.w, v.z, v.y, v.x);
+    report(
+        "Vec3::dot",
+        time(|| {
+            black_box(black_box(a).dot(black_box(b)));
+        }),
+        time(|| {
+            black_box(black_box(ga).dot(black_box(gb)));
+        }),
+    );
+    report(
+        "Vec4::dot",
+        time(|| {
+            black_box(black_box(v).dot(black_box(w)));
+        }),
+        time(|| {
+            black_box(black_box(gv).dot(black_box(gw)));
+        }),
+    );
+    report(
+        "Vec3::cross",
+        time(|| {
+            black_box(black_box(a).cross(black_box(b)));
+        }),
+        time(|| {
+            black_box(black_box(ga).cross(black_box(gb)));
+        }),
+    );
+
+    let p = Quaternion::new(0.4, Vec3::new(0.0, 0.6, 0.8_f32));
+    let q = Quaternion::new(-1.1, Vec3::new(0.8, 0.0, 0.6_f32));
+    let gp = Quaternion::new_sv(g(p.w), p.v.map(g));
+    let gq = Quaternion::new_sv(g(q.w), q.v.map(g));
+    report(
+        "Quaternion * Quaternion",
+        time(|| {
+            black_box(black_box(p) * black_box(q));
+        }),
+        time(|| {
+            black_box(black_box(gp) * black_box(gq));
+        }),
+    );
+}
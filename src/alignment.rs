@@ -0,0 +1,101 @@
+use num_traits::cast;
+use num_traits::identities;
+
+use crate::matrix::Mat3;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+///A similarity transform mapping a point set onto another: `dst = scale * rotation * src + translation`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Alignment<T> {
+    pub rotation: Mat3<T>,
+    pub translation: Vec3<T>,
+    ///Always one for the rigid alignment returned by `kabsch`
+    pub scale: T,
+    ///Root mean square distance between the transformed source points and their targets
+    pub rmsd: T,
+}
+
+impl<T: FloatScalar> Alignment<T> {
+    #[inline]
+    pub fn transform_point(&self, point: Vec3<T>) -> Vec3<T> {
+        self.rotation * point * self.scale + self.translation
+    }
+}
+
+///Best-fit rotation and translation taking each point of `src` to the point of `dst` with the
+///same index, minimizing the squared distances. The result is always a proper rotation.
+///
+///Returns `None` if the slices are empty or have different lengths.
+pub fn kabsch<T: FloatScalar>(src: &[Vec3<T>], dst: &[Vec3<T>]) -> Option<Alignment<T>> {
+    align(src, dst, false)
+}
+
+///Same as `kabsch`, but also finds the uniform scale that best fits `src` to `dst`
+///(Umeyama, 1991).
+pub fn umeyama<T: FloatScalar>(src: &[Vec3<T>], dst: &[Vec3<T>]) -> Option<Alignment<T>> {
+    align(src, dst, true)
+}
+
+fn align<T: FloatScalar>(
+    src: &[Vec3<T>],
+    dst: &[Vec3<T>],
+    with_scale: bool,
+) -> Option<Alignment<T>> {
+    if src.is_empty() || src.len() != dst.len() {
+        return None;
+    }
+
+    let zero = identities::zero::<T>();
+    let one = identities::one::<T>();
+    let n = cast::cast::<usize, T>(src.len()).unwrap();
+
+    let src_centroid = src.iter().fold(Vec3::default(), |acc, &p| acc + p) / n;
+    let dst_centroid = dst.iter().fold(Vec3::default(), |acc, &p| acc + p) / n;
+
+    //cross covariance H = sum((p - cp) * (q - cq)^T), built column by column
+    let mut columns = [Vec3::default(); 3];
+    let mut src_variance = zero;
+    for (&p, &q) in src.iter().zip(dst) {
+        let p = p - src_centroid;
+        let q = q - dst_centroid;
+
+        for (j, column) in columns.iter_mut().enumerate() {
+            *column += p * q[j];
+        }
+        src_variance += p.squared_mag();
+    }
+    let h = Mat3::from((columns[0], columns[1], columns[2]));
+
+    let (u, s, v) = h.svd();
+
+    //flip the smallest axis if the best orthogonal fit is a reflection
+    let d = if (v.determinant() * u.determinant()) < zero {
+        -one
+    } else {
+        one
+    };
+    let rotation =
+        v * Mat3::from([[one, zero, zero], [zero, one, zero], [zero, zero, d]]) * u.transpost();
+
+    let scale = if with_scale && src_variance > zero {
+        (s.x + s.y + s.z * d) / src_variance
+    } else {
+        one
+    };
+    let translation = dst_centroid - rotation * src_centroid * scale;
+
+    let mut alignment = Alignment {
+        rotation,
+        translation,
+        scale,
+        rmsd: zero,
+    };
+
+    let squared_error = src.iter().zip(dst).fold(zero, |acc, (&p, &q)| {
+        acc + (alignment.transform_point(p) - q).squared_mag()
+    });
+    alignment.rmsd = (squared_error / n).sqrt();
+
+    Some(alignment)
+}
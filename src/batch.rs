@@ -0,0 +1,107 @@
+use core::array;
+
+use crate::matrix::Mat4;
+use crate::quaternions::Quaternion;
+use crate::vectors::{Vec3, Vec3x8};
+use crate::{BatchScalar, FloatScalar, RealScalar};
+
+//the slices are split in chunks of this many vectors, one per task
+#[cfg(feature = "rayon")]
+const TASK_LEN: usize = 4096;
+
+//runs `f` over chunks of the slice in parallel, or over the whole slice when it is short
+#[cfg(feature = "rayon")]
+#[inline]
+fn for_each_chunk<T: Send>(items: &mut [T], f: impl Fn(&mut [T]) + Send + Sync) {
+    if items.len() > TASK_LEN {
+        use rayon::prelude::*;
+
+        items.par_chunks_mut(TASK_LEN).for_each(f);
+        return;
+    }
+
+    f(items)
+}
+
+//runs `f` over the whole slice
+#[cfg(not(feature = "rayon"))]
+#[inline]
+fn for_each_chunk<T>(items: &mut [T], f: impl Fn(&mut [T])) {
+    f(items)
+}
+
+//maps eight vectors at a time through `wide`, and the remainder one by one through `single`
+#[inline]
+fn map_wide<T: Copy>(
+    items: &mut [Vec3<T>],
+    wide: impl Fn(Vec3x8<T>) -> Vec3x8<T>,
+    single: impl Fn(Vec3<T>) -> Vec3<T>,
+) {
+    let mut chunks = items.chunks_exact_mut(Vec3x8::<T>::LANES);
+
+    for chunk in &mut chunks {
+        wide(Vec3x8::from_slice(chunk)).write_to_slice(chunk);
+    }
+    for v in chunks.into_remainder() {
+        *v = single(*v);
+    }
+}
+
+impl<T: RealScalar + BatchScalar> Mat4<T> {
+    ///Transforms every point of `points` in place, with an implicit `w` of one. The last row is
+    ///ignored, use `project_slice` for projections
+    pub fn transform_points(&self, points: &mut [Vec3<T>]) {
+        for_each_chunk(points, |chunk| {
+            map_wide(chunk, |p| *self * p, |p| self.transform_point(p))
+        });
+    }
+
+    ///Transforms every direction of `vectors` in place, ignoring the translation
+    pub fn transform_vectors(&self, vectors: &mut [Vec3<T>]) {
+        let mut linear = *self;
+        linear[3] = [T::zero(), T::zero(), T::zero(), T::one()];
+
+        for_each_chunk(vectors, |chunk| {
+            map_wide(chunk, |v| linear * v, |v| linear.transform_point(v))
+        });
+    }
+
+    ///Transforms every point of `points` in place and divides it by its `w`, as a projection
+    ///matrix followed by the perspective divide
+    pub fn project_slice(&self, points: &mut [Vec3<T>]) {
+        let m = self;
+        let project_wide = |p: Vec3x8<T>| {
+            let inv: [T; 8] = array::from_fn(|l| {
+                T::one() / (m[0][3] * p.x[l] + m[1][3] * p.y[l] + m[2][3] * p.z[l] + m[3][3])
+            });
+
+            *m * p * Vec3x8::new(inv, inv, inv)
+        };
+        let project = |p: Vec3<T>| {
+            let w = m[0][3] * p.x + m[1][3] * p.y + m[2][3] * p.z + m[3][3];
+            m.transform_point(p) * (T::one() / w)
+        };
+
+        for_each_chunk(points, |chunk| map_wide(chunk, project_wide, project));
+    }
+
+    //the same expression as `Mat4 * Vec3x8`, for a single point
+    #[inline]
+    fn transform_point(&self, p: Vec3<T>) -> Vec3<T> {
+        let m = self;
+        let row = |r: usize| m[0][r] * p.x + m[1][r] * p.y + m[2][r] * p.z + m[3][r];
+
+        Vec3::new(row(0), row(1), row(2))
+    }
+}
+
+impl<T: FloatScalar + BatchScalar> Quaternion<T> {
+    ///Rotates every vector of `vectors` in place by the unit Quaternion `self`
+    pub fn rotate_slice(&self, vectors: &mut [Vec3<T>]) {
+        let q = *self;
+
+        for_each_chunk(vectors, |chunk| {
+            map_wide(chunk, |v| v.rotate(q), |v| q.rotate(v))
+        });
+    }
+}
@@ -0,0 +1,125 @@
+use num_traits::cast;
+
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+///An axis aligned bounding box described by its `min` and `max` corners
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Aabb<T> {
+    pub min: Vec3<T>,
+    pub max: Vec3<T>,
+}
+
+#[allow(dead_code)]
+impl<T> Aabb<T> {
+    #[inline]
+    pub const fn new(min: Vec3<T>, max: Vec3<T>) -> Self {
+        Aabb { min, max }
+    }
+}
+
+impl<T: FloatScalar> Aabb<T> {
+    ///Returns an inverted box that contains nothing. Growing it by any point or box yields that point or box
+    #[inline]
+    pub fn empty() -> Self {
+        let inf = T::infinity();
+        Aabb {
+            min: Vec3::new(inf, inf, inf),
+            max: Vec3::new(-inf, -inf, -inf),
+        }
+    }
+
+    ///Returns the smallest box containing all the `points`
+    pub fn from_points(points: &[Vec3<T>]) -> Self {
+        points
+            .iter()
+            .fold(Aabb::empty(), |aabb, &point| aabb.grow(point))
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    ///Returns the box expanded to also contain `point`
+    #[inline]
+    pub fn grow(self, point: Vec3<T>) -> Self {
+        Aabb {
+            min: Vec3::new(
+                self.min.x.min(point.x),
+                self.min.y.min(point.y),
+                self.min.z.min(point.z),
+            ),
+            max: Vec3::new(
+                self.max.x.max(point.x),
+                self.max.y.max(point.y),
+                self.max.z.max(point.z),
+            ),
+        }
+    }
+
+    ///Returns the smallest box containing both `self` and `other`
+    #[inline]
+    pub fn union(self, other: Aabb<T>) -> Self {
+        self.grow(other.min).grow(other.max)
+    }
+
+    #[inline]
+    pub fn center(&self) -> Vec3<T> {
+        (self.min + self.max) * cast::cast::<f64, T>(0.5).unwrap()
+    }
+
+    ///Returns the length of the box along each axis
+    #[inline]
+    pub fn extent(&self) -> Vec3<T> {
+        self.max - self.min
+    }
+
+    #[inline]
+    pub fn surface_area(&self) -> T {
+        if self.is_empty() {
+            return T::zero();
+        }
+
+        let e = self.extent();
+        let two = T::one() + T::one();
+        two * (e.x * e.y + e.y * e.z + e.z * e.x)
+    }
+
+    #[inline]
+    pub fn contains(&self, point: Vec3<T>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    ///Returns true if the boxes touch or overlap
+    #[inline]
+    pub fn intersects(&self, other: &Aabb<T>) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    ///Returns the point inside the box closest to `point`
+    #[inline]
+    pub fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            point.x.max(self.min.x).min(self.max.x),
+            point.y.max(self.min.y).min(self.max.y),
+            point.z.max(self.min.z).min(self.max.z),
+        )
+    }
+
+    ///Squared distance from `point` to the box. Zero if the point is inside
+    #[inline]
+    pub fn distance_squared(&self, point: Vec3<T>) -> T {
+        (self.closest_point(point) - point).squared_mag()
+    }
+}
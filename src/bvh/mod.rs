@@ -0,0 +1,424 @@
+mod aabb;
+mod ray;
+
+pub use aabb::Aabb;
+pub use ray::Ray;
+
+#[cfg(feature = "alloc")]
+use num_traits::{cast, ToPrimitive};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::vectors::Vec3;
+#[cfg(feature = "alloc")]
+use crate::FloatScalar;
+
+//number of buckets the centroids are sorted into when evaluating the SAH
+#[cfg(feature = "alloc")]
+const BINS: usize = 12;
+//nodes with this many primitives or less are allowed to become leaves
+#[cfg(feature = "alloc")]
+const MAX_LEAF_SIZE: usize = 4;
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Copy, Clone)]
+struct Node<T> {
+    bounds: Aabb<T>,
+    //for leaves the first primitive in `indices`, otherwise the index of the left child.
+    //The right child is always stored right after the left one
+    start: usize,
+    //number of primitives in a leaf, zero for interior nodes
+    count: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Node<T> {
+    #[inline]
+    fn is_leaf(&self) -> bool {
+        self.count > 0
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Copy, Clone)]
+struct Bin<T> {
+    bounds: Aabb<T>,
+    count: usize,
+}
+
+///A bounding volume hierarchy over primitives given by their bounding boxes.
+///
+///The tree only stores indices to the primitives, so queries take a callback to test the
+///actual geometry. Built top-down with a binned surface area heuristic.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct Bvh<T> {
+    nodes: Vec<Node<T>>,
+    indices: Vec<usize>,
+    primitives: Vec<Aabb<T>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FloatScalar> Bvh<T> {
+    ///Builds the hierarchy over the primitives bounded by `bounds`. The position of each box
+    ///in the slice is the index reported back by the queries
+    pub fn build(bounds: &[Aabb<T>]) -> Self {
+        let mut bvh = Bvh {
+            nodes: Vec::with_capacity(bounds.len() * 2),
+            indices: (0..bounds.len()).collect(),
+            primitives: bounds.to_vec(),
+        };
+
+        if bounds.is_empty() {
+            return bvh;
+        }
+
+        let centroids: Vec<Vec3<T>> = bounds.iter().map(|b| b.center()).collect();
+        let root = bounds.iter().fold(Aabb::empty(), |acc, &b| acc.union(b));
+
+        bvh.nodes.push(Node {
+            bounds: root,
+            start: 0,
+            count: bounds.len(),
+        });
+
+        //split with an explicit stack, skewed inputs can make the tree too deep to recurse
+        let mut stack = Vec::with_capacity(64);
+        stack.push(0);
+        while let Some(node) = stack.pop() {
+            if let Some(left) = bvh.subdivide(node, &centroids) {
+                stack.push(left + 1);
+                stack.push(left);
+            }
+        }
+        bvh
+    }
+
+    //splits `node` in two and returns the index of its left child, or None if it stays a leaf
+    fn subdivide(&mut self, node: usize, centroids: &[Vec3<T>]) -> Option<usize> {
+        let Node {
+            bounds: node_bounds,
+            start,
+            count,
+        } = self.nodes[node];
+
+        if count <= 1 {
+            return None;
+        }
+
+        let centroid_bounds = self.indices[start..start + count]
+            .iter()
+            .fold(Aabb::empty(), |acc, &i| acc.grow(centroids[i]));
+
+        //None when every centroid is in the same spot, there is no way to separate them
+        let (axis, split, cost) = self.find_split(start, count, centroids, &centroid_bounds)?;
+
+        let leaf_cost = cast::cast::<usize, T>(count).unwrap() * node_bounds.surface_area();
+        if cost >= leaf_cost && count <= MAX_LEAF_SIZE {
+            return None;
+        }
+
+        //partition the primitives in place around the chosen bin
+        let lo = centroid_bounds.min[axis];
+        let scale = cast::cast::<usize, T>(BINS).unwrap() / (centroid_bounds.max[axis] - lo);
+        let mut i = start;
+        let mut j = start + count;
+
+        while i < j {
+            if bin_index(centroids[self.indices[i]][axis], lo, scale) < split {
+                i += 1;
+            } else {
+                j -= 1;
+                self.indices.swap(i, j);
+            }
+        }
+
+        let left_count = i - start;
+        if left_count == 0 || left_count == count {
+            return None;
+        }
+
+        let left = self.nodes.len();
+        let left_bounds = self.indices[start..i]
+            .iter()
+            .fold(Aabb::empty(), |acc, &p| acc.union(self.primitives[p]));
+        let right_bounds = self.indices[i..start + count]
+            .iter()
+            .fold(Aabb::empty(), |acc, &p| acc.union(self.primitives[p]));
+
+        self.nodes.push(Node {
+            bounds: left_bounds,
+            start,
+            count: left_count,
+        });
+        self.nodes.push(Node {
+            bounds: right_bounds,
+            start: i,
+            count: count - left_count,
+        });
+        self.nodes[node].start = left;
+        self.nodes[node].count = 0;
+
+        Some(left)
+    }
+
+    //returns the axis, the first bin of the right side and the SAH cost of the cheapest split
+    fn find_split(
+        &self,
+        start: usize,
+        count: usize,
+        centroids: &[Vec3<T>],
+        centroid_bounds: &Aabb<T>,
+    ) -> Option<(usize, usize, T)> {
+        let mut best: Option<(usize, usize, T)> = None;
+
+        for axis in 0..3 {
+            let lo = centroid_bounds.min[axis];
+            let extent = centroid_bounds.max[axis] - lo;
+            if extent <= T::zero() {
+                continue;
+            }
+
+            let scale = cast::cast::<usize, T>(BINS).unwrap() / extent;
+            let mut bins = [Bin {
+                bounds: Aabb::empty(),
+                count: 0,
+            }; BINS];
+
+            for &p in &self.indices[start..start + count] {
+                let bin = &mut bins[bin_index(centroids[p][axis], lo, scale)];
+                bin.bounds = bin.bounds.union(self.primitives[p]);
+                bin.count += 1;
+            }
+
+            //sweep from the right storing the cost of everything at or after each plane
+            let mut right_cost = [T::zero(); BINS];
+            let mut acc = Aabb::empty();
+            let mut acc_count = 0;
+            for b in (1..BINS).rev() {
+                acc = acc.union(bins[b].bounds);
+                acc_count += bins[b].count;
+                right_cost[b] = acc.surface_area() * cast::cast::<usize, T>(acc_count).unwrap();
+            }
+
+            let mut acc = Aabb::empty();
+            let mut acc_count = 0;
+            for b in 1..BINS {
+                acc = acc.union(bins[b - 1].bounds);
+                acc_count += bins[b - 1].count;
+
+                //planes with every primitive on the same side do not split anything
+                if acc_count == 0 || acc_count == count {
+                    continue;
+                }
+
+                let cost =
+                    acc.surface_area() * cast::cast::<usize, T>(acc_count).unwrap() + right_cost[b];
+                let better = match best {
+                    Some((_, _, c)) => cost < c,
+                    None => true,
+                };
+                if better {
+                    best = Some((axis, b, cost));
+                }
+            }
+        }
+        best
+    }
+
+    ///Recomputes the bounds of every node after the primitives moved. The hierarchy itself
+    ///is kept, so queries stay correct but may get slower if the geometry deforms too much
+    pub fn refit(&mut self, bounds: &[Aabb<T>]) {
+        assert_eq!(
+            bounds.len(),
+            self.indices.len(),
+            "refit needs the same number of primitives the Bvh was built with"
+        );
+
+        self.primitives.copy_from_slice(bounds);
+
+        //children are always pushed after their parent, so walking backwards visits them first
+        for n in (0..self.nodes.len()).rev() {
+            let node = self.nodes[n];
+            self.nodes[n].bounds = if node.is_leaf() {
+                self.indices[node.start..node.start + node.count]
+                    .iter()
+                    .fold(Aabb::empty(), |acc, &p| acc.union(self.primitives[p]))
+            } else {
+                self.nodes[node.start]
+                    .bounds
+                    .union(self.nodes[node.start + 1].bounds)
+            };
+        }
+    }
+
+    ///Number of primitives in the hierarchy
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    ///Bounds of the whole hierarchy
+    #[inline]
+    pub fn bounds(&self) -> Aabb<T> {
+        self.nodes.first().map_or(Aabb::empty(), |root| root.bounds)
+    }
+
+    ///Finds the closest primitive hit by `ray` before `max_t`.
+    ///
+    ///`hit` is called with the index of every primitive whose bounds are crossed by the ray and
+    ///should return the distance along the ray where it hits the primitive, if it does.
+    ///Returns the index of the closest primitive and its distance.
+    pub fn ray_cast<F>(&self, ray: &Ray<T>, max_t: T, mut hit: F) -> Option<(usize, T)>
+    where
+        F: FnMut(usize, &Ray<T>) -> Option<T>,
+    {
+        let mut closest: Option<(usize, T)> = None;
+        let mut max_t = max_t;
+        let mut stack = Vec::with_capacity(64);
+
+        if let Some(root) = self.nodes.first() {
+            if let Some(t) = ray.intersect_aabb(&root.bounds, max_t) {
+                stack.push((0, t));
+            }
+        }
+
+        while let Some((n, entry)) = stack.pop() {
+            //something closer was found after this node was pushed
+            if entry > max_t {
+                continue;
+            }
+
+            let node = &self.nodes[n];
+            if node.is_leaf() {
+                for &p in &self.indices[node.start..node.start + node.count] {
+                    if ray.intersect_aabb(&self.primitives[p], max_t).is_none() {
+                        continue;
+                    }
+
+                    if let Some(t) = hit(p, ray) {
+                        if t >= T::zero() && t <= max_t {
+                            max_t = t;
+                            closest = Some((p, t));
+                        }
+                    }
+                }
+                continue;
+            }
+
+            let left = ray.intersect_aabb(&self.nodes[node.start].bounds, max_t);
+            let right = ray.intersect_aabb(&self.nodes[node.start + 1].bounds, max_t);
+
+            //push the farthest child first so the nearest one is visited next
+            match (left, right) {
+                (Some(l), Some(r)) if l < r => {
+                    stack.push((node.start + 1, r));
+                    stack.push((node.start, l));
+                }
+                (Some(l), Some(r)) => {
+                    stack.push((node.start, l));
+                    stack.push((node.start + 1, r));
+                }
+                (Some(l), None) => stack.push((node.start, l)),
+                (None, Some(r)) => stack.push((node.start + 1, r)),
+                (None, None) => {}
+            }
+        }
+        closest
+    }
+
+    ///Finds the primitive closest to `point`.
+    ///
+    ///`closest_point` is called with the index of the candidate primitives and should return
+    ///the point on that primitive closest to `point`. Returns the index of the nearest primitive
+    ///and the point found on it.
+    pub fn nearest<F>(&self, point: Vec3<T>, mut closest_point: F) -> Option<(usize, Vec3<T>)>
+    where
+        F: FnMut(usize, Vec3<T>) -> Vec3<T>,
+    {
+        let mut nearest: Option<(usize, Vec3<T>)> = None;
+        let mut best = T::infinity();
+        let mut stack = Vec::with_capacity(64);
+
+        if let Some(root) = self.nodes.first() {
+            stack.push((0, root.bounds.distance_squared(point)));
+        }
+
+        while let Some((n, dist)) = stack.pop() {
+            if dist > best {
+                continue;
+            }
+
+            let node = &self.nodes[n];
+            if node.is_leaf() {
+                for &p in &self.indices[node.start..node.start + node.count] {
+                    if self.primitives[p].distance_squared(point) > best {
+                        continue;
+                    }
+
+                    let candidate = closest_point(p, point);
+                    let d = (candidate - point).squared_mag();
+                    if d < best {
+                        best = d;
+                        nearest = Some((p, candidate));
+                    }
+                }
+                continue;
+            }
+
+            let l = self.nodes[node.start].bounds.distance_squared(point);
+            let r = self.nodes[node.start + 1].bounds.distance_squared(point);
+
+            if l < r {
+                stack.push((node.start + 1, r));
+                stack.push((node.start, l));
+            } else {
+                stack.push((node.start, l));
+                stack.push((node.start + 1, r));
+            }
+        }
+        nearest
+    }
+
+    ///Returns the index of every primitive whose bounds overlap `region`
+    pub fn overlapping(&self, region: &Aabb<T>) -> Vec<usize> {
+        let mut ret = Vec::new();
+        let mut stack = Vec::with_capacity(64);
+
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+
+        while let Some(n) = stack.pop() {
+            let node = &self.nodes[n];
+            if !node.bounds.intersects(region) {
+                continue;
+            }
+
+            if node.is_leaf() {
+                ret.extend(
+                    self.indices[node.start..node.start + node.count]
+                        .iter()
+                        .filter(|&&p| self.primitives[p].intersects(region)),
+                );
+            } else {
+                stack.push(node.start);
+                stack.push(node.start + 1);
+            }
+        }
+        ret
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[inline]
+fn bin_index<T: FloatScalar>(value: T, lo: T, scale: T) -> usize {
+    ((value - lo) * scale).to_usize().unwrap_or(0).min(BINS - 1)
+}
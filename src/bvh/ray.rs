@@ -0,0 +1,88 @@
+use crate::bvh::Aabb;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+///A half-line starting at `origin` and going towards `direction`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ray<T> {
+    pub origin: Vec3<T>,
+    pub direction: Vec3<T>,
+}
+
+#[allow(dead_code)]
+impl<T> Ray<T> {
+    #[inline]
+    pub const fn new(origin: Vec3<T>, direction: Vec3<T>) -> Self {
+        Ray { origin, direction }
+    }
+}
+
+impl<T: FloatScalar> Ray<T> {
+    ///Returns the point at `origin + direction * t`
+    #[inline]
+    pub fn at(&self, t: T) -> Vec3<T> {
+        self.origin + self.direction * t
+    }
+
+    ///Slab test against `aabb`. Returns the distance where the ray enters the box
+    ///(zero if the origin is inside), or `None` if it misses or enters after `max_t`
+    pub fn intersect_aabb(&self, aabb: &Aabb<T>, max_t: T) -> Option<T> {
+        let one = T::one();
+        let mut t_min = T::zero();
+        let mut t_max = max_t;
+
+        for axis in 0..3 {
+            let inv = one / self.direction[axis];
+            let mut t0 = (aabb.min[axis] - self.origin[axis]) * inv;
+            let mut t1 = (aabb.max[axis] - self.origin[axis]) * inv;
+
+            if inv < T::zero() {
+                core::mem::swap(&mut t0, &mut t1);
+            }
+
+            //min/max discard the NaN produced when the origin lies on a slab of a parallel ray
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+
+            if t_max < t_min {
+                return None;
+            }
+        }
+        Some(t_min)
+    }
+
+    ///Möller–Trumbore intersection with the triangle `(a, b, c)`. Both faces are hit
+    pub fn intersect_triangle(&self, a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Option<T> {
+        let zero = T::zero();
+        let one = T::one();
+
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = self.direction.cross(edge2);
+        let det = edge1.dot(p);
+
+        if det.abs() < T::epsilon() {
+            return None;
+        }
+
+        let inv_det = one / det;
+        let s = self.origin - a;
+        let u = s.dot(p) * inv_det;
+        if u < zero || u > one {
+            return None;
+        }
+
+        let q = s.cross(edge1);
+        let v = self.direction.dot(q) * inv_det;
+        if v < zero || u + v > one {
+            return None;
+        }
+
+        let t = edge2.dot(q) * inv_det;
+        if t >= zero {
+            Some(t)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use alloc::vec::Vec;
+
+use crate::collision::gjk::{gjk, support, tolerance, Gjk, SupportPoint};
+use crate::collision::SupportMap;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+//curved shapes converge slowly, this bounds the error on deep sphere contacts to about a percent
+const MAX_ITERATIONS: usize = 128;
+
+#[derive(Debug, Copy, Clone)]
+struct Face<T> {
+    idx: [usize; 3],
+    normal: Vec3<T>,
+    dist: T,
+}
+
+impl<T: FloatScalar> Face<T> {
+    //the winding of `idx` decides where the face points to
+    fn new(points: &[SupportPoint<T>], idx: [usize; 3]) -> Self {
+        let a = points[idx[0]].w;
+        let n = (points[idx[1]].w - a).cross(points[idx[2]].w - a);
+        let len = n.magnitude();
+
+        if len <= T::epsilon() {
+            //degenerate sliver, never picked as the closest face nor seen from any point
+            return Face {
+                idx,
+                normal: Vec3::default(),
+                dist: T::infinity(),
+            };
+        }
+
+        let normal = n / len;
+        Face {
+            idx,
+            normal,
+            dist: normal.dot(a),
+        }
+    }
+}
+
+///Smallest translation that moves `b` out of `a`, found with the Expanding Polytope Algorithm.
+///
+///The direction of the returned vector is the contact normal and its length the penetration
+///depth. Returns `None` if the shapes don't intersect.
+pub fn epa_penetration<T, A, B>(a: &A, b: &B) -> Option<Vec3<T>>
+where
+    T: FloatScalar,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let simplex = match gjk(a, b) {
+        Gjk::Intersecting(simplex) => simplex,
+        Gjk::Separated { .. } => return None,
+    };
+
+    let mut points: Vec<SupportPoint<T>> = simplex.points[..simplex.len].to_vec();
+    if !complete_tetrahedron(a, b, &mut points) {
+        //the difference is flat, so the shapes are only touching
+        return Some(Vec3::default());
+    }
+
+    //wind every face so it points away from the inside of the tetrahedron
+    let inner = (points[0].w + points[1].w + points[2].w + points[3].w)
+        / (T::one() + T::one() + T::one() + T::one());
+    let mut faces: Vec<Face<T>> = [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+        .iter()
+        .map(|&[i, j, k]| {
+            let n = (points[j].w - points[i].w).cross(points[k].w - points[i].w);
+            if n.dot(points[i].w - inner) < T::zero() {
+                Face::new(&points, [i, k, j])
+            } else {
+                Face::new(&points, [i, j, k])
+            }
+        })
+        .collect();
+
+    let tol = tolerance::<T>();
+    let mut closest = faces[0];
+
+    for _ in 0..MAX_ITERATIONS {
+        closest = *faces
+            .iter()
+            .min_by(|f, g| {
+                f.dist
+                    .partial_cmp(&g.dist)
+                    .unwrap_or(core::cmp::Ordering::Equal)
+            })
+            .unwrap();
+
+        let p = support(a, b, closest.normal);
+        if p.w.dot(closest.normal) - closest.dist <= tol * (T::one() + closest.dist.abs()) {
+            break;
+        }
+
+        let new = points.len();
+        points.push(p);
+
+        //remove every face the new point can see, keeping the boundary of the hole
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        faces.retain(|face| {
+            if face.normal.dot(p.w - points[face.idx[0]].w) <= T::zero() {
+                return true;
+            }
+
+            for e in 0..3 {
+                let edge = (face.idx[e], face.idx[(e + 1) % 3]);
+                match horizon
+                    .iter()
+                    .position(|&(i, j)| i == edge.1 && j == edge.0)
+                {
+                    Some(shared) => {
+                        horizon.swap_remove(shared);
+                    }
+                    None => horizon.push(edge),
+                }
+            }
+            false
+        });
+
+        if horizon.is_empty() {
+            break;
+        }
+
+        for (i, j) in horizon {
+            faces.push(Face::new(&points, [i, j, new]));
+        }
+    }
+    Some(closest.normal * closest.dist.max(T::zero()))
+}
+
+//grows the simplex left by GJK into a tetrahedron, returns false if the Minkowski difference is flat
+fn complete_tetrahedron<T, A, B>(a: &A, b: &B, points: &mut Vec<SupportPoint<T>>) -> bool
+where
+    T: FloatScalar,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let zero = T::zero();
+    let one = T::one();
+    let tol = tolerance::<T>();
+
+    if points.len() == 1 {
+        let axes = [
+            Vec3::new(one, zero, zero),
+            Vec3::new(-one, zero, zero),
+            Vec3::new(zero, one, zero),
+            Vec3::new(zero, -one, zero),
+            Vec3::new(zero, zero, one),
+            Vec3::new(zero, zero, -one),
+        ];
+
+        match axes
+            .iter()
+            .map(|&dir| support(a, b, dir))
+            .find(|p| (p.w - points[0].w).squared_mag() > tol)
+        {
+            Some(p) => points.push(p),
+            None => return false,
+        }
+    }
+
+    if points.len() == 2 {
+        let d = points[1].w - points[0].w;
+        //cross with the axis least aligned with the segment to get a perpendicular
+        let axis = if d.x.abs() <= d.y.abs() && d.x.abs() <= d.z.abs() {
+            Vec3::new(one, zero, zero)
+        } else if d.y.abs() <= d.z.abs() {
+            Vec3::new(zero, one, zero)
+        } else {
+            Vec3::new(zero, zero, one)
+        };
+        let n = d.cross(axis);
+        let m = d.cross(n);
+
+        match [n, -n, m, -m]
+            .iter()
+            .map(|&dir| support(a, b, dir))
+            .find(|p| (p.w - points[0].w).cross(d).squared_mag() > tol * d.squared_mag())
+        {
+            Some(p) => points.push(p),
+            None => return false,
+        }
+    }
+
+    if points.len() == 3 {
+        let n = (points[1].w - points[0].w).cross(points[2].w - points[0].w);
+
+        match [n, -n]
+            .iter()
+            .map(|&dir| support(a, b, dir))
+            .find(|p| (p.w - points[0].w).dot(n).abs() > tol * n.magnitude())
+        {
+            Some(p) => points.push(p),
+            None => return false,
+        }
+    }
+    true
+}
@@ -0,0 +1,298 @@
+use num_traits::cast;
+
+use crate::collision::SupportMap;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+const MAX_ITERATIONS: usize = 64;
+
+///A vertex of the Minkowski difference `a - b`, remembering which point of `a` produced it
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct SupportPoint<T> {
+    pub w: Vec3<T>,
+    pub a: Vec3<T>,
+}
+
+#[inline]
+pub(crate) fn support<T, A, B>(a: &A, b: &B, dir: Vec3<T>) -> SupportPoint<T>
+where
+    T: FloatScalar,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let pa = a.support(dir);
+    let pb = b.support(-dir);
+
+    SupportPoint { w: pa - pb, a: pa }
+}
+
+#[inline]
+pub(crate) fn tolerance<T: FloatScalar>() -> T {
+    T::epsilon() * cast::cast::<f64, T>(128.0).unwrap()
+}
+
+//the vertices of a reduced simplex given as indices into the previous one with their barycentric weights
+struct Reduced<T> {
+    idx: [usize; 3],
+    weights: [T; 3],
+    len: usize,
+}
+
+impl<T: FloatScalar> Reduced<T> {
+    #[inline]
+    fn point(i: usize) -> Self {
+        Reduced {
+            idx: [i, 0, 0],
+            weights: [T::one(), T::zero(), T::zero()],
+            len: 1,
+        }
+    }
+
+    #[inline]
+    fn edge(i: usize, j: usize, t: T) -> Self {
+        Reduced {
+            idx: [i, j, 0],
+            weights: [T::one() - t, t, T::zero()],
+            len: 2,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct Simplex<T> {
+    pub points: [SupportPoint<T>; 4],
+    weights: [T; 4],
+    pub len: usize,
+}
+
+impl<T: FloatScalar> Simplex<T> {
+    fn new(first: SupportPoint<T>) -> Self {
+        Simplex {
+            points: [first; 4],
+            weights: [T::one(), T::zero(), T::zero(), T::zero()],
+            len: 1,
+        }
+    }
+
+    fn contains(&self, w: Vec3<T>) -> bool {
+        self.points[..self.len]
+            .iter()
+            .any(|p| (p.w - w).squared_mag() <= tolerance::<T>() * tolerance::<T>())
+    }
+
+    fn push(&mut self, point: SupportPoint<T>) {
+        self.points[self.len] = point;
+        self.len += 1;
+    }
+
+    //reduces the simplex to the smallest sub-simplex containing the point closest to the origin
+    //and returns that point, or `None` if the simplex encloses the origin
+    fn solve(&mut self) -> Option<Vec3<T>> {
+        let p = self.points;
+        let reduced = match self.len {
+            1 => Reduced::point(0),
+            2 => closest_segment(p[0].w, p[1].w, [0, 1]),
+            3 => closest_triangle(p[0].w, p[1].w, p[2].w, [0, 1, 2]),
+            _ => closest_tetrahedron(&p)?,
+        };
+
+        let mut v = Vec3::default();
+        for i in 0..reduced.len {
+            self.points[i] = p[reduced.idx[i]];
+            self.weights[i] = reduced.weights[i];
+            v += p[reduced.idx[i]].w * reduced.weights[i];
+        }
+        self.len = reduced.len;
+        Some(v)
+    }
+
+    //the points of `a` and `b` that map to the current closest point
+    fn closest_points(&self, v: Vec3<T>) -> (Vec3<T>, Vec3<T>) {
+        let mut pa = Vec3::default();
+        for i in 0..self.len {
+            pa += self.points[i].a * self.weights[i];
+        }
+        (pa, pa - v)
+    }
+}
+
+fn closest_segment<T: FloatScalar>(a: Vec3<T>, b: Vec3<T>, idx: [usize; 2]) -> Reduced<T> {
+    let ab = b - a;
+    let len = ab.squared_mag();
+    let t = if len > T::zero() {
+        -a.dot(ab) / len
+    } else {
+        T::zero()
+    };
+
+    if t <= T::zero() {
+        Reduced::point(idx[0])
+    } else if t >= T::one() {
+        Reduced::point(idx[1])
+    } else {
+        Reduced::edge(idx[0], idx[1], t)
+    }
+}
+
+//Voronoi region test of the origin against the triangle, see Ericson's Real-Time Collision Detection 5.1.5
+fn closest_triangle<T: FloatScalar>(
+    a: Vec3<T>,
+    b: Vec3<T>,
+    c: Vec3<T>,
+    idx: [usize; 3],
+) -> Reduced<T> {
+    let zero = T::zero();
+    let ab = b - a;
+    let ac = c - a;
+
+    let d1 = -ab.dot(a);
+    let d2 = -ac.dot(a);
+    if d1 <= zero && d2 <= zero {
+        return Reduced::point(idx[0]);
+    }
+
+    let d3 = -ab.dot(b);
+    let d4 = -ac.dot(b);
+    if d3 >= zero && d4 <= d3 {
+        return Reduced::point(idx[1]);
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= zero && d1 >= zero && d3 <= zero {
+        return Reduced::edge(idx[0], idx[1], d1 / (d1 - d3));
+    }
+
+    let d5 = -ab.dot(c);
+    let d6 = -ac.dot(c);
+    if d6 >= zero && d5 <= d6 {
+        return Reduced::point(idx[2]);
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= zero && d2 >= zero && d6 <= zero {
+        return Reduced::edge(idx[0], idx[2], d2 / (d2 - d6));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+        return Reduced::edge(idx[1], idx[2], (d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    let denom = T::one() / (va + vb + vc);
+    let v = vb * denom;
+    let w = vc * denom;
+    Reduced {
+        idx,
+        weights: [T::one() - v - w, v, w],
+        len: 3,
+    }
+}
+
+fn closest_tetrahedron<T: FloatScalar>(p: &[SupportPoint<T>; 4]) -> Option<Reduced<T>> {
+    //each face with the vertex opposite to it
+    const FACES: [[usize; 4]; 4] = [[0, 1, 2, 3], [0, 2, 3, 1], [0, 3, 1, 2], [1, 3, 2, 0]];
+
+    let mut best: Option<(Reduced<T>, T)> = None;
+
+    for &[i, j, k, l] in FACES.iter() {
+        let n = (p[j].w - p[i].w).cross(p[k].w - p[i].w);
+        let origin_side = -p[i].w.dot(n);
+        let opposite_side = (p[l].w - p[i].w).dot(n);
+
+        //the origin is on the same side of this face as the rest of the tetrahedron
+        if origin_side * opposite_side > T::zero() {
+            continue;
+        }
+
+        let reduced = closest_triangle(p[i].w, p[j].w, p[k].w, [i, j, k]);
+        let mut v = Vec3::default();
+        for m in 0..reduced.len {
+            v += p[reduced.idx[m]].w * reduced.weights[m];
+        }
+
+        let dist = v.squared_mag();
+        let better = match &best {
+            Some((_, d)) => dist < *d,
+            None => true,
+        };
+        if better {
+            best = Some((reduced, dist));
+        }
+    }
+    best.map(|(reduced, _)| reduced)
+}
+
+pub(crate) enum Gjk<T> {
+    Separated { simplex: Simplex<T>, v: Vec3<T> },
+    Intersecting(Simplex<T>),
+}
+
+pub(crate) fn gjk<T, A, B>(a: &A, b: &B) -> Gjk<T>
+where
+    T: FloatScalar,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let tol = tolerance::<T>();
+    let first = support(a, b, Vec3::new(T::one(), T::zero(), T::zero()));
+    let mut simplex = Simplex::new(first);
+    let mut v = first.w;
+
+    for _ in 0..MAX_ITERATIONS {
+        let vv = v.squared_mag();
+        if vv <= tol * tol {
+            return Gjk::Intersecting(simplex);
+        }
+
+        let w = support(a, b, -v);
+
+        //no further progress towards the origin can be made
+        if vv - v.dot(w.w) <= tol * vv || simplex.contains(w.w) {
+            break;
+        }
+
+        simplex.push(w);
+        v = match simplex.solve() {
+            Some(v) => v,
+            None => return Gjk::Intersecting(simplex),
+        };
+    }
+    Gjk::Separated { simplex, v }
+}
+
+///Returns true if the convex shapes `a` and `b` touch or overlap
+pub fn gjk_intersects<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: FloatScalar,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    matches!(gjk(a, b), Gjk::Intersecting(_))
+}
+
+///Distance between the convex shapes `a` and `b`, or `None` if they intersect
+pub fn gjk_distance<T, A, B>(a: &A, b: &B) -> Option<T>
+where
+    T: FloatScalar,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated { v, .. } => Some(v.magnitude()),
+        Gjk::Intersecting(_) => None,
+    }
+}
+
+///The closest pair of points between the convex shapes `a` and `b`, the first on `a` and the
+///second on `b`. Returns `None` if they intersect
+pub fn gjk_closest_points<T, A, B>(a: &A, b: &B) -> Option<(Vec3<T>, Vec3<T>)>
+where
+    T: FloatScalar,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated { simplex, v } => Some(simplex.closest_points(v)),
+        Gjk::Intersecting(_) => None,
+    }
+}
@@ -0,0 +1,11 @@
+#[cfg(feature = "alloc")]
+mod epa;
+mod gjk;
+mod support;
+
+#[cfg(feature = "alloc")]
+pub use epa::epa_penetration;
+pub use gjk::{gjk_closest_points, gjk_distance, gjk_intersects};
+#[cfg(feature = "alloc")]
+pub use support::ConvexHull;
+pub use support::{Capsule, Cuboid, Sphere, SupportMap, Transformed};
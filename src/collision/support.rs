@@ -0,0 +1,181 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::matrix::Mat3;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+///A convex shape described by its support function
+pub trait SupportMap<T> {
+    ///Returns the point of the shape farthest along `dir`. `dir` does not need to be normalized
+    fn support(&self, dir: Vec3<T>) -> Vec3<T>;
+}
+
+impl<T, S: SupportMap<T> + ?Sized> SupportMap<T> for &S {
+    #[inline]
+    fn support(&self, dir: Vec3<T>) -> Vec3<T> {
+        (**self).support(dir)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Sphere<T> {
+    pub center: Vec3<T>,
+    pub radius: T,
+}
+
+#[allow(dead_code)]
+impl<T> Sphere<T> {
+    #[inline]
+    pub const fn new(center: Vec3<T>, radius: T) -> Self {
+        Sphere { center, radius }
+    }
+}
+
+impl<T: FloatScalar> SupportMap<T> for Sphere<T> {
+    #[inline]
+    fn support(&self, dir: Vec3<T>) -> Vec3<T> {
+        let len = dir.magnitude();
+        if len > T::zero() {
+            self.center + dir * (self.radius / len)
+        } else {
+            self.center + Vec3::new(self.radius, T::zero(), T::zero())
+        }
+    }
+}
+
+///An axis aligned box. Wrap it in a `Transformed` to rotate it
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Cuboid<T> {
+    pub center: Vec3<T>,
+    pub half_extents: Vec3<T>,
+}
+
+#[allow(dead_code)]
+impl<T> Cuboid<T> {
+    #[inline]
+    pub const fn new(center: Vec3<T>, half_extents: Vec3<T>) -> Self {
+        Cuboid {
+            center,
+            half_extents,
+        }
+    }
+}
+
+impl<T: FloatScalar> SupportMap<T> for Cuboid<T> {
+    #[inline]
+    fn support(&self, dir: Vec3<T>) -> Vec3<T> {
+        let h = self.half_extents;
+        let pick = |d: T, e: T| if d >= T::zero() { e } else { -e };
+
+        self.center + Vec3::new(pick(dir.x, h.x), pick(dir.y, h.y), pick(dir.z, h.z))
+    }
+}
+
+///The set of points within `radius` of the segment from `a` to `b`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Capsule<T> {
+    pub a: Vec3<T>,
+    pub b: Vec3<T>,
+    pub radius: T,
+}
+
+#[allow(dead_code)]
+impl<T> Capsule<T> {
+    #[inline]
+    pub const fn new(a: Vec3<T>, b: Vec3<T>, radius: T) -> Self {
+        Capsule { a, b, radius }
+    }
+}
+
+impl<T: FloatScalar> SupportMap<T> for Capsule<T> {
+    #[inline]
+    fn support(&self, dir: Vec3<T>) -> Vec3<T> {
+        let end = if self.a.dot(dir) >= self.b.dot(dir) {
+            self.a
+        } else {
+            self.b
+        };
+
+        Sphere::new(end, self.radius).support(dir)
+    }
+}
+
+///The convex hull of a point cloud. The points don't need to be on the hull themselves
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvexHull<T> {
+    points: Vec<Vec3<T>>,
+}
+
+#[cfg(feature = "alloc")]
+#[allow(dead_code)]
+impl<T> ConvexHull<T> {
+    ///Returns None if `points` is empty, there is no hull to take the support of
+    #[inline]
+    pub fn new(points: Vec<Vec3<T>>) -> Option<Self> {
+        if points.is_empty() {
+            None
+        } else {
+            Some(ConvexHull { points })
+        }
+    }
+
+    ///The points the hull was built from, never empty
+    #[inline]
+    pub fn points(&self) -> &[Vec3<T>] {
+        &self.points
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FloatScalar> SupportMap<T> for ConvexHull<T> {
+    fn support(&self, dir: Vec3<T>) -> Vec3<T> {
+        let mut best = self.points[0];
+        let mut best_dot = best.dot(dir);
+
+        for &p in &self.points[1..] {
+            let d = p.dot(dir);
+            if d > best_dot {
+                best = p;
+                best_dot = d;
+            }
+        }
+        best
+    }
+}
+
+///A shape rotated by `rotation` and then moved by `translation`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Transformed<S, T> {
+    pub shape: S,
+    pub rotation: Mat3<T>,
+    pub translation: Vec3<T>,
+}
+
+#[allow(dead_code)]
+impl<S, T> Transformed<S, T> {
+    #[inline]
+    pub const fn new(shape: S, rotation: Mat3<T>, translation: Vec3<T>) -> Self {
+        Transformed {
+            shape,
+            rotation,
+            translation,
+        }
+    }
+}
+
+impl<T: FloatScalar, S: SupportMap<T>> SupportMap<T> for Transformed<S, T> {
+    #[inline]
+    fn support(&self, dir: Vec3<T>) -> Vec3<T> {
+        let r = &self.rotation;
+
+        //the rotation is orthonormal, so its inverse is the transpose: dot with each column
+        let local = Vec3::new(
+            Vec3::from(r[0]).dot(dir),
+            Vec3::from(r[1]).dot(dir),
+            Vec3::from(r[2]).dot(dir),
+        );
+        *r * self.shape.support(local) + self.translation
+    }
+}
@@ -0,0 +1,275 @@
+use num_traits::{identities, Num, NumCast, One, ToPrimitive, Zero};
+
+use core::ops::*;
+
+use crate::{FloatScalar, RealScalar, Scalar};
+
+///A complex number `re + im * i`.
+///
+///It implements `RealScalar`, so it can be used as the element of any vector or matrix, for
+///example for complex `Mat2` algebra.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+#[allow(dead_code)]
+impl<T> Complex<T> {
+    #[inline]
+    pub const fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+}
+
+impl<T: Scalar> Complex<T> {
+    ///The imaginary unit
+    #[inline]
+    pub fn i() -> Self {
+        Complex::new(identities::zero::<T>(), identities::one::<T>())
+    }
+
+    #[inline]
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T: RealScalar> Complex<T> {
+    #[inline]
+    pub fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+}
+
+impl<T: FloatScalar> Complex<T> {
+    ///`r * (cos(theta) + i * sin(theta))`
+    #[inline]
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    ///The unit complex number with the angle `theta`
+    #[inline]
+    pub fn cis(theta: T) -> Self {
+        Complex::new(theta.cos(), theta.sin())
+    }
+
+    #[inline]
+    pub fn norm(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    ///The angle in radians, in the range `(-pi, pi]`
+    #[inline]
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    #[inline]
+    pub fn to_polar(self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+
+    #[inline]
+    pub fn inv(self) -> Self {
+        let k = identities::one::<T>() / self.norm_sqr();
+        Complex::new(self.re * k, -self.im * k)
+    }
+
+    #[inline]
+    pub fn exp(self) -> Self {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    ///Principal natural logarithm
+    #[inline]
+    pub fn ln(self) -> Self {
+        Complex::new(self.norm().ln(), self.arg())
+    }
+
+    ///Principal square root, with a non negative real part
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        let two = identities::one::<T>() + identities::one::<T>();
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.sqrt(), theta / two)
+    }
+
+    ///Raises `self` to the real power `exp` using the principal branch
+    #[inline]
+    pub fn powf(self, exp: T) -> Self {
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.powf(exp), theta * exp)
+    }
+}
+
+impl<T: Scalar> Add<Complex<T>> for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Complex<T>) -> Self {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl<T: Scalar> Sub<Complex<T>> for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Complex<T>) -> Self {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl<T: Scalar> Mul<Complex<T>> for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Complex<T>) -> Self {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl<T: Scalar> Div<Complex<T>> for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: Complex<T>) -> Self {
+        let den = other.norm_sqr();
+        Complex::new(
+            (self.re * other.re + self.im * other.im) / den,
+            (self.im * other.re - self.re * other.im) / den,
+        )
+    }
+}
+
+impl<T: Scalar> Rem<Complex<T>> for Complex<T> {
+    type Output = Self;
+
+    //the remainder of the division truncated to a gaussian integer
+    #[inline]
+    fn rem(self, other: Complex<T>) -> Self {
+        let one = identities::one::<T>();
+        let q = self / other;
+        let gaussian = Complex::new(q.re - q.re % one, q.im - q.im % one);
+        self - other * gaussian
+    }
+}
+
+impl<T: RealScalar> Neg for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: T) -> Self {
+        Complex::new(self.re * other, self.im * other)
+    }
+}
+
+impl<T: Scalar> Div<T> for Complex<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: T) -> Self {
+        Complex::new(self.re / other, self.im / other)
+    }
+}
+
+macro_rules! impl_complex_assign {
+    ($($Trait:ident, $method:ident, $op:tt);*) => {
+        $(
+            impl<T: Scalar> $Trait for Complex<T> {
+                #[inline]
+                fn $method(&mut self, other: Self) {
+                    *self = *self $op other;
+                }
+            }
+        )*
+    };
+}
+
+impl_complex_assign!(
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /;
+    RemAssign, rem_assign, %
+);
+
+impl<T: Scalar> Zero for Complex<T> {
+    #[inline]
+    fn zero() -> Self {
+        Complex::new(identities::zero::<T>(), identities::zero::<T>())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T: Scalar> One for Complex<T> {
+    #[inline]
+    fn one() -> Self {
+        Complex::new(identities::one::<T>(), identities::zero::<T>())
+    }
+}
+
+impl<T: Scalar> Num for Complex<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    ///Parses a real number
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(|re| Complex::new(re, identities::zero::<T>()))
+    }
+}
+
+//only complex numbers on the real axis convert to primitive numbers
+impl<T: Scalar> ToPrimitive for Complex<T> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        if self.im.is_zero() {
+            self.re.to_i64()
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        if self.im.is_zero() {
+            self.re.to_u64()
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        if self.im.is_zero() {
+            self.re.to_f64()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Scalar> NumCast for Complex<T> {
+    #[inline]
+    fn from<U: ToPrimitive>(n: U) -> Option<Self> {
+        <T as NumCast>::from(n).map(|re| Complex::new(re, identities::zero::<T>()))
+    }
+}
@@ -0,0 +1,248 @@
+use core::array;
+use core::fmt::{self, Display, Formatter, LowerExp, Write};
+
+use crate::complex::Complex;
+use crate::dual::DualN;
+use crate::euler::Euler;
+use crate::matrix::{Mat2, Mat3, Mat4};
+use crate::quaternions::Quaternion;
+use crate::rotor::{Bivector3, Rotor2, Rotor3};
+use crate::vectors::{Vec2, Vec3, Vec4};
+use crate::{FloatScalar, RealScalar};
+
+//formats a value with `LowerExp` where `Display` is expected
+#[derive(Copy, Clone)]
+struct Exp<T>(T);
+
+impl<T: LowerExp> Display for Exp<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        LowerExp::fmt(&self.0, f)
+    }
+}
+
+//counts the characters written to it, to align the matrix columns
+struct Counter(usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+//the options of the formatter applied to each element: `{:+8.3}` pads every element to 8
+//characters with 3 decimals and an explicit sign
+#[derive(Copy, Clone)]
+struct Style {
+    width: usize,
+    precision: Option<usize>,
+    plus: bool,
+}
+
+impl Style {
+    fn of(f: &Formatter) -> Self {
+        Style {
+            width: f.width().unwrap_or(0),
+            precision: f.precision(),
+            plus: f.sign_plus(),
+        }
+    }
+
+    fn write<W: Write, E: Display>(self, out: &mut W, x: &E) -> fmt::Result {
+        let w = self.width;
+        match (self.precision, self.plus) {
+            (Some(p), true) => write!(out, "{:>+w$.p$}", x),
+            (Some(p), false) => write!(out, "{:>w$.p$}", x),
+            (None, true) => write!(out, "{:>+w$}", x),
+            (None, false) => write!(out, "{:>w$}", x),
+        }
+    }
+}
+
+//`(x, y, z)`, or `(yaw: a, pitch: b, roll: c)` with labels
+fn write_fields<E: Display>(f: &mut Formatter, fields: &[(&str, E)]) -> fmt::Result {
+    let style = Style::of(f);
+
+    f.write_char('(')?;
+    for (i, (label, x)) in fields.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        if !label.is_empty() {
+            write!(f, "{}: ", label)?;
+        }
+        style.write(f, x)?;
+    }
+    f.write_char(')')
+}
+
+//one row per line, with the columns right aligned
+fn write_matrix<E: Display, const N: usize>(
+    f: &mut Formatter,
+    columns: [[E; N]; N],
+) -> fmt::Result {
+    let style = Style::of(f);
+    let mut widths = [style.width; N];
+
+    for (width, column) in widths.iter_mut().zip(columns.iter()) {
+        for x in column {
+            let mut counter = Counter(0);
+            Style { width: 0, ..style }.write(&mut counter, x)?;
+            *width = (*width).max(counter.0);
+        }
+    }
+
+    for row in 0..N {
+        if row > 0 {
+            f.write_char('\n')?;
+        }
+        f.write_char('[')?;
+        for (col, column) in columns.iter().enumerate() {
+            if col > 0 {
+                f.write_str(", ")?;
+            }
+            Style {
+                width: widths[col],
+                ..style
+            }
+            .write(f, &column[row])?;
+        }
+        f.write_char(']')?;
+    }
+    Ok(())
+}
+
+//whether `x` is written with a minus sign, which unlike `x < 0` also catches negative zeros
+fn is_negative<E: Display>(style: Style, x: &E) -> bool {
+    struct Sign(Option<bool>);
+
+    impl Write for Sign {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if self.0.is_none() && !s.is_empty() {
+                self.0 = Some(s.starts_with('-'));
+            }
+            Ok(())
+        }
+    }
+
+    let mut sign = Sign(None);
+    let style = Style {
+        width: 0,
+        plus: false,
+        ..style
+    };
+    style.write(&mut sign, x).is_ok() && sign.0 == Some(true)
+}
+
+//`a + bi - cj`, the sign of every term after the first one is taken out of its coefficient
+fn write_terms<T: RealScalar, E: Display>(
+    f: &mut Formatter,
+    terms: &[(T, &str)],
+    wrap: impl Fn(T) -> E,
+) -> fmt::Result {
+    let style = Style::of(f);
+
+    for (i, &(x, basis)) in terms.iter().enumerate() {
+        if i == 0 {
+            style.write(f, &wrap(x))?;
+        } else if is_negative(style, &wrap(x)) {
+            f.write_str(" - ")?;
+            Style {
+                plus: false,
+                ..style
+            }
+            .write(f, &wrap(-x))?;
+        } else {
+            f.write_str(" + ")?;
+            Style {
+                plus: false,
+                ..style
+            }
+            .write(f, &wrap(x))?;
+        }
+        f.write_str(basis)?;
+    }
+    Ok(())
+}
+
+//implements `Display` and `LowerExp` with the same body, which formats the scalars it gets out
+//of `$wrap`
+macro_rules! impl_fmt {
+    (
+        $Type:ty; $T:ident: $($bound:path),+ $(; const $N:ident)?;
+        |$self:ident, $f:ident, $wrap:ident| $body:expr
+    ) => {
+        impl<$T: Display $(+ $bound)+ $(, const $N: usize)?> Display for $Type {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let ($self, $f, $wrap) = (self, f, |x: $T| x);
+                $body
+            }
+        }
+
+        impl<$T: LowerExp $(+ $bound)+ $(, const $N: usize)?> LowerExp for $Type {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let ($self, $f, $wrap) = (self, f, Exp::<$T>);
+                $body
+            }
+        }
+    };
+}
+
+impl_fmt!(Vec2<T>; T: Copy; |v, f, wrap| {
+    write_fields(f, &[("", wrap(v.x)), ("", wrap(v.y))])
+});
+
+impl_fmt!(Vec3<T>; T: Copy; |v, f, wrap| {
+    write_fields(f, &[("", wrap(v.x)), ("", wrap(v.y)), ("", wrap(v.z))])
+});
+
+impl_fmt!(Vec4<T>; T: Copy; |v, f, wrap| {
+    write_fields(f, &[("", wrap(v.x)), ("", wrap(v.y)), ("", wrap(v.z)), ("", wrap(v.w))])
+});
+
+impl_fmt!(Mat2<T>; T: Copy; |m, f, wrap| {
+    write_matrix::<_, 2>(f, array::from_fn(|col| array::from_fn(|row| wrap(m[col][row]))))
+});
+
+impl_fmt!(Mat3<T>; T: Copy; |m, f, wrap| {
+    write_matrix::<_, 3>(f, array::from_fn(|col| array::from_fn(|row| wrap(m[col][row]))))
+});
+
+impl_fmt!(Mat4<T>; T: Copy; |m, f, wrap| {
+    write_matrix::<_, 4>(f, array::from_fn(|col| array::from_fn(|row| wrap(m[col][row]))))
+});
+
+impl_fmt!(Quaternion<T>; T: FloatScalar; |q, f, wrap| {
+    write_terms(f, &[(q.w, ""), (q.v.x, "i"), (q.v.y, "j"), (q.v.z, "k")], wrap)
+});
+
+impl_fmt!(Euler<T>; T: FloatScalar; |e, f, wrap| {
+    write_fields(f, &[("yaw", wrap(e.yaw)), ("pitch", wrap(e.pitch)), ("roll", wrap(e.row))])
+});
+
+impl_fmt!(Complex<T>; T: RealScalar; |c, f, wrap| {
+    write_terms(f, &[(c.re, ""), (c.im, "i")], wrap)
+});
+
+impl_fmt!(Bivector3<T>; T: RealScalar; |b, f, wrap| {
+    write_terms(f, &[(b.xy, " e12"), (b.yz, " e23"), (b.zx, " e31")], wrap)
+});
+
+impl_fmt!(Rotor2<T>; T: FloatScalar; |r, f, wrap| {
+    let c = r.complex();
+    write_terms(f, &[(c.re, ""), (c.im, " e12")], wrap)
+});
+
+impl_fmt!(Rotor3<T>; T: RealScalar; |r, f, wrap| {
+    let b = r.b;
+    write_terms(f, &[(r.s, ""), (b.xy, " e12"), (b.yz, " e23"), (b.zx, " e31")], wrap)
+});
+
+//`re + (d0, d1)ε`
+impl_fmt!(DualN<T, N>; T: Copy; const N; |d, f, wrap| {
+    let style = Style::of(f);
+    style.write(f, &wrap(d.re))?;
+    f.write_str(" + ")?;
+    write_fields::<_>(f, &d.du.map(|x| ("", wrap(x))))?;
+    f.write_char('ε')
+});
@@ -0,0 +1,621 @@
+use num_traits::{cast, identities, Float, Num, NumCast, One, ToPrimitive, Zero};
+
+use core::cmp::Ordering;
+use core::num::FpCategory;
+use core::ops::*;
+
+use crate::FloatScalar;
+
+///A dual number carrying the partial derivatives of a value with respect to `N` variables, for
+///forward mode automatic differentiation.
+///
+///It implements `FloatScalar`, so every vector, matrix and Quaternion method propagates the
+///derivatives alongside the values. Comparisons only look at the real part.
+#[derive(Debug, Copy, Clone)]
+pub struct DualN<T, const N: usize> {
+    ///The value
+    pub re: T,
+    ///The partial derivatives of the value
+    pub du: [T; N],
+}
+
+///A dual number with a single derivative
+pub type Dual<T> = DualN<T, 1>;
+
+impl<T, const N: usize> DualN<T, N> {
+    #[inline]
+    pub const fn new(re: T, du: [T; N]) -> Self {
+        DualN { re, du }
+    }
+}
+
+impl<T: FloatScalar, const N: usize> DualN<T, N> {
+    ///A value that doesn't depend on any variable
+    #[inline]
+    pub fn constant(re: T) -> Self {
+        DualN {
+            re,
+            du: [identities::zero::<T>(); N],
+        }
+    }
+
+    ///The `i`-th variable, with a unit derivative with respect to itself
+    #[inline]
+    pub fn variable(re: T, i: usize) -> Self {
+        let mut ret = DualN::constant(re);
+        ret.du[i] = identities::one::<T>();
+        ret
+    }
+
+    //applies the chain rule for a function with value `re` and derivative `d` at `self.re`
+    #[inline]
+    fn chain(self, re: T, d: T) -> Self {
+        DualN {
+            re,
+            du: self.du.map(|x| x * d),
+        }
+    }
+}
+
+impl<T: FloatScalar> Dual<T> {
+    ///The derivative of a single variable dual number
+    #[inline]
+    pub fn derivative(&self) -> T {
+        self.du[0]
+    }
+}
+
+///Computes the Jacobian of `f` at `x` by evaluating it once with dual numbers.
+///
+///The Jacobian is returned column major like the matrices: element `[j][i]` is the derivative of
+///the `i`-th output with respect to the `j`-th input.
+pub fn jacobian<T, F, const N: usize, const M: usize>(f: F, x: [T; N]) -> [[T; M]; N]
+where
+    T: FloatScalar,
+    F: Fn([DualN<T, N>; N]) -> [DualN<T, N>; M],
+{
+    let mut input = [DualN::constant(identities::zero::<T>()); N];
+    for (i, value) in input.iter_mut().enumerate() {
+        *value = DualN::variable(x[i], i);
+    }
+
+    let output = f(input);
+    let mut ret = [[identities::zero::<T>(); M]; N];
+    for (j, column) in ret.iter_mut().enumerate() {
+        for (i, value) in column.iter_mut().enumerate() {
+            *value = output[i].du[j];
+        }
+    }
+    ret
+}
+
+impl<T: FloatScalar, const N: usize> Default for DualN<T, N> {
+    #[inline]
+    fn default() -> Self {
+        DualN::constant(identities::zero::<T>())
+    }
+}
+
+impl<T: FloatScalar, const N: usize> PartialEq for DualN<T, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.re == other.re
+    }
+}
+
+impl<T: FloatScalar, const N: usize> PartialOrd for DualN<T, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<T: FloatScalar, const N: usize> Neg for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        DualN {
+            re: -self.re,
+            du: self.du.map(|x| -x),
+        }
+    }
+}
+
+impl<T: FloatScalar, const N: usize> Add for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: Self) -> Self {
+        self.re += other.re;
+        for (a, b) in self.du.iter_mut().zip(other.du.iter()) {
+            *a += *b;
+        }
+        self
+    }
+}
+
+impl<T: FloatScalar, const N: usize> Sub for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: Self) -> Self {
+        self.re -= other.re;
+        for (a, b) in self.du.iter_mut().zip(other.du.iter()) {
+            *a -= *b;
+        }
+        self
+    }
+}
+
+impl<T: FloatScalar, const N: usize> Mul for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: Self) -> Self {
+        for (a, b) in self.du.iter_mut().zip(other.du.iter()) {
+            *a = *a * other.re + self.re * *b;
+        }
+        self.re *= other.re;
+        self
+    }
+}
+
+impl<T: FloatScalar, const N: usize> Div for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(mut self, other: Self) -> Self {
+        let inv = identities::one::<T>() / other.re;
+        for (a, b) in self.du.iter_mut().zip(other.du.iter()) {
+            *a = (*a * other.re - self.re * *b) * inv * inv;
+        }
+        self.re /= other.re;
+        self
+    }
+}
+
+impl<T: FloatScalar, const N: usize> Rem for DualN<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, other: Self) -> Self {
+        //x % y = x - trunc(x / y) * y, where the truncation is locally constant
+        let q = (self.re / other.re).trunc();
+        DualN {
+            re: self.re % other.re,
+            du: self.du,
+        } - DualN {
+            re: identities::zero::<T>(),
+            du: other.du.map(|x| x * q),
+        }
+    }
+}
+
+macro_rules! impl_dual_assign {
+    ($($Trait:ident, $method:ident, $op:tt);*) => {
+        $(
+            impl<T: FloatScalar, const N: usize> $Trait for DualN<T, N> {
+                #[inline]
+                fn $method(&mut self, other: Self) {
+                    *self = *self $op other;
+                }
+            }
+        )*
+    };
+}
+
+impl_dual_assign!(
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /;
+    RemAssign, rem_assign, %
+);
+
+impl<T: FloatScalar, const N: usize> Zero for DualN<T, N> {
+    #[inline]
+    fn zero() -> Self {
+        DualN::constant(identities::zero::<T>())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero()
+    }
+}
+
+impl<T: FloatScalar, const N: usize> One for DualN<T, N> {
+    #[inline]
+    fn one() -> Self {
+        DualN::constant(identities::one::<T>())
+    }
+}
+
+impl<T: FloatScalar, const N: usize> Num for DualN<T, N> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(DualN::constant)
+    }
+}
+
+impl<T: FloatScalar, const N: usize> ToPrimitive for DualN<T, N> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.re.to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.re.to_u64()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        self.re.to_f32()
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        self.re.to_f64()
+    }
+}
+
+impl<T: FloatScalar, const N: usize> NumCast for DualN<T, N> {
+    #[inline]
+    fn from<U: ToPrimitive>(n: U) -> Option<Self> {
+        <T as NumCast>::from(n).map(DualN::constant)
+    }
+}
+
+impl<T: FloatScalar, const N: usize> Float for DualN<T, N> {
+    #[inline]
+    fn nan() -> Self {
+        DualN::constant(T::nan())
+    }
+
+    #[inline]
+    fn infinity() -> Self {
+        DualN::constant(T::infinity())
+    }
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        DualN::constant(T::neg_infinity())
+    }
+
+    #[inline]
+    fn neg_zero() -> Self {
+        DualN::constant(T::neg_zero())
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        DualN::constant(T::min_value())
+    }
+
+    #[inline]
+    fn min_positive_value() -> Self {
+        DualN::constant(T::min_positive_value())
+    }
+
+    #[inline]
+    fn epsilon() -> Self {
+        DualN::constant(T::epsilon())
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        DualN::constant(T::max_value())
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.re.is_nan()
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.re.is_infinite()
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        self.re.is_finite()
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.re.is_normal()
+    }
+
+    #[inline]
+    fn classify(self) -> FpCategory {
+        self.re.classify()
+    }
+
+    #[inline]
+    fn floor(self) -> Self {
+        DualN::constant(self.re.floor())
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        DualN::constant(self.re.ceil())
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        DualN::constant(self.re.round())
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        DualN::constant(self.re.trunc())
+    }
+
+    #[inline]
+    fn fract(self) -> Self {
+        DualN {
+            re: self.re.fract(),
+            du: self.du,
+        }
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        if self.re.is_sign_negative() {
+            -self
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        DualN::constant(self.re.signum())
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.re.is_sign_positive()
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.re.is_sign_negative()
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        let inv = self.re.recip();
+        self.chain(inv, -inv * inv)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return DualN::one();
+        }
+        let n_t = cast::cast::<i32, T>(n).unwrap();
+        self.chain(self.re.powi(n), n_t * self.re.powi(n - 1))
+    }
+
+    fn powf(self, n: Self) -> Self {
+        let re = self.re.powf(n.re);
+        let mut ret = self.chain(re, n.re * self.re.powf(n.re - identities::one::<T>()));
+
+        //only add the derivative through the exponent where there is one, ln(0) would spoil it
+        let ln = self.re.ln();
+        for (a, b) in ret.du.iter_mut().zip(n.du.iter()) {
+            if !b.is_zero() {
+                *a += re * ln * *b;
+            }
+        }
+        ret
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        let re = self.re.sqrt();
+        //the derivative blows up at zero, but a constant still has none
+        if self.du.iter().all(|x| x.is_zero()) {
+            return DualN { re, du: self.du };
+        }
+        self.chain(re, (re + re).recip())
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        let re = self.re.exp();
+        self.chain(re, re)
+    }
+
+    #[inline]
+    fn exp2(self) -> Self {
+        let re = self.re.exp2();
+        self.chain(
+            re,
+            re * cast::cast::<f64, T>(core::f64::consts::LN_2).unwrap(),
+        )
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.chain(self.re.ln(), self.re.recip())
+    }
+
+    #[inline]
+    fn log(self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    #[inline]
+    fn log2(self) -> Self {
+        self.chain(
+            self.re.log2(),
+            (self.re * cast::cast::<f64, T>(core::f64::consts::LN_2).unwrap()).recip(),
+        )
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        self.chain(
+            self.re.log10(),
+            (self.re * cast::cast::<f64, T>(core::f64::consts::LN_10).unwrap()).recip(),
+        )
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        if self.re >= other.re || other.re.is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        if self.re <= other.re || other.re.is_nan() {
+            self
+        } else {
+            other
+        }
+    }
+
+    #[inline]
+    fn abs_sub(self, other: Self) -> Self {
+        if self.re <= other.re {
+            DualN::zero()
+        } else {
+            self - other
+        }
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        let re = self.re.cbrt();
+        let three = cast::cast::<f64, T>(3.0).unwrap();
+        self.chain(re, (three * re * re).recip())
+    }
+
+    #[inline]
+    fn hypot(self, other: Self) -> Self {
+        let re = self.re.hypot(other.re);
+        let inv = re.recip();
+        let mut ret = self.chain(re, self.re * inv);
+        for (a, b) in ret.du.iter_mut().zip(other.du.iter()) {
+            *a += other.re * inv * *b;
+        }
+        ret
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        self.chain(sin, cos)
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        let (sin, cos) = self.re.sin_cos();
+        self.chain(cos, -sin)
+    }
+
+    #[inline]
+    fn tan(self) -> Self {
+        let re = self.re.tan();
+        self.chain(re, identities::one::<T>() + re * re)
+    }
+
+    #[inline]
+    fn asin(self) -> Self {
+        let d = (identities::one::<T>() - self.re * self.re).sqrt().recip();
+        self.chain(self.re.asin(), d)
+    }
+
+    #[inline]
+    fn acos(self) -> Self {
+        let d = (identities::one::<T>() - self.re * self.re).sqrt().recip();
+        self.chain(self.re.acos(), -d)
+    }
+
+    #[inline]
+    fn atan(self) -> Self {
+        let d = (identities::one::<T>() + self.re * self.re).recip();
+        self.chain(self.re.atan(), d)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        //d(atan2(y, x)) = (x * dy - y * dx) / (x^2 + y^2)
+        let inv = (self.re * self.re + other.re * other.re).recip();
+        let mut ret = self.chain(self.re.atan2(other.re), other.re * inv);
+        for (a, b) in ret.du.iter_mut().zip(other.du.iter()) {
+            *a -= self.re * inv * *b;
+        }
+        ret
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.re.sin_cos();
+        (self.chain(sin, cos), self.chain(cos, -sin))
+    }
+
+    #[inline]
+    fn exp_m1(self) -> Self {
+        self.chain(self.re.exp_m1(), self.re.exp())
+    }
+
+    #[inline]
+    fn ln_1p(self) -> Self {
+        self.chain(self.re.ln_1p(), (identities::one::<T>() + self.re).recip())
+    }
+
+    #[inline]
+    fn sinh(self) -> Self {
+        self.chain(self.re.sinh(), self.re.cosh())
+    }
+
+    #[inline]
+    fn cosh(self) -> Self {
+        self.chain(self.re.cosh(), self.re.sinh())
+    }
+
+    #[inline]
+    fn tanh(self) -> Self {
+        let re = self.re.tanh();
+        self.chain(re, identities::one::<T>() - re * re)
+    }
+
+    #[inline]
+    fn asinh(self) -> Self {
+        let d = (self.re * self.re + identities::one::<T>()).sqrt().recip();
+        self.chain(self.re.asinh(), d)
+    }
+
+    #[inline]
+    fn acosh(self) -> Self {
+        let d = (self.re * self.re - identities::one::<T>()).sqrt().recip();
+        self.chain(self.re.acosh(), d)
+    }
+
+    #[inline]
+    fn atanh(self) -> Self {
+        let d = (identities::one::<T>() - self.re * self.re).recip();
+        self.chain(self.re.atanh(), d)
+    }
+
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.re.integer_decode()
+    }
+}
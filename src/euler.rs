@@ -2,13 +2,14 @@ use num_traits::cast;
 use num_traits::float::FloatConst;
 use num_traits::identities;
 
-use std::convert::From;
+use core::convert::From;
 
 use super::FloatScalar;
 use super::Mat3;
 use super::Mat4;
 use super::Quaternion;
 
+#[repr(C)]
 #[derive(Debug, Copy, Clone, Default, PartialEq)]
 pub struct Euler<T: FloatScalar> {
     pub yaw: T,
@@ -21,6 +22,16 @@ impl<T: FloatScalar> Euler<T> {
     pub fn new(yaw: T, pitch: T, row: T) -> Self {
         Euler { yaw, pitch, row }
     }
+
+    ///Converts the angles to `U`, returns `None` if any of them can't be represented
+    #[inline]
+    pub fn cast<U: FloatScalar>(self) -> Option<Euler<U>> {
+        Some(Euler {
+            yaw: U::from(self.yaw)?,
+            pitch: U::from(self.pitch)?,
+            row: U::from(self.row)?,
+        })
+    }
 }
 
 impl<T: FloatScalar + FloatConst> From<Mat3<T>> for Euler<T> {
@@ -0,0 +1,92 @@
+use num_traits::cast;
+use num_traits::identities;
+
+use crate::matrix::Mat3;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+///Principal component analysis of a point set
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Pca<T> {
+    pub centroid: Vec3<T>,
+    ///Unit principal axes stored as the columns, from the most to the least spread out.
+    ///They form a right-handed orthonormal basis
+    pub axes: Mat3<T>,
+    ///Variance of the points along each axis, in decreasing order
+    pub variances: Vec3<T>,
+}
+
+impl<T: FloatScalar> Pca<T> {
+    ///Direction in which the points are most spread out
+    #[inline]
+    pub fn major_axis(&self) -> Vec3<T> {
+        Vec3::from(self.axes[0])
+    }
+
+    ///Direction in which the points are least spread out
+    #[inline]
+    pub fn minor_axis(&self) -> Vec3<T> {
+        Vec3::from(self.axes[2])
+    }
+}
+
+///Average position of the points. Returns `None` if `points` is empty
+pub fn centroid<T: FloatScalar>(points: &[Vec3<T>]) -> Option<Vec3<T>> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let n = cast::cast::<usize, T>(points.len()).unwrap();
+    Some(points.iter().fold(Vec3::default(), |acc, &p| acc + p) / n)
+}
+
+///Population covariance matrix of the points. Returns `None` if `points` is empty
+pub fn covariance<T: FloatScalar>(points: &[Vec3<T>]) -> Option<Mat3<T>> {
+    let center = centroid(points)?;
+    let n = cast::cast::<usize, T>(points.len()).unwrap();
+
+    let mut columns = [Vec3::default(); 3];
+    for &p in points {
+        let d = p - center;
+        for (j, column) in columns.iter_mut().enumerate() {
+            *column += d * d[j];
+        }
+    }
+
+    Some(Mat3::from((columns[0] / n, columns[1] / n, columns[2] / n)))
+}
+
+///Principal axes of the points. Returns `None` if `points` is empty
+pub fn pca<T: FloatScalar>(points: &[Vec3<T>]) -> Option<Pca<T>> {
+    let center = centroid(points)?;
+    let (variances, axes) = covariance(points)?.symmetric_eigen();
+
+    //make the basis right-handed so it can be used as a rotation
+    let axes = if axes.determinant() < identities::zero::<T>() {
+        Mat3::from((
+            Vec3::from(axes[0]),
+            Vec3::from(axes[1]),
+            -Vec3::from(axes[2]),
+        ))
+    } else {
+        axes
+    };
+
+    Some(Pca {
+        centroid: center,
+        axes,
+        variances,
+    })
+}
+
+///Least squares plane through the points, returned as a point on the plane and its unit normal.
+///Returns `None` if `points` is empty
+pub fn fit_plane<T: FloatScalar>(points: &[Vec3<T>]) -> Option<(Vec3<T>, Vec3<T>)> {
+    pca(points).map(|pca| (pca.centroid, pca.minor_axis()))
+}
+
+///Least squares line through the points, returned as a point on the line and its unit direction.
+///Returns `None` if `points` is empty
+pub fn fit_line<T: FloatScalar>(points: &[Vec3<T>]) -> Option<(Vec3<T>, Vec3<T>)> {
+    pca(points).map(|pca| (pca.centroid, pca.major_axis()))
+}
@@ -0,0 +1,85 @@
+use crate::matrix::{Mat2, Mat3, Mat4};
+use crate::quaternions::Quaternion;
+use crate::vectors::{Vec2, Vec3, Vec4};
+use crate::FloatScalar;
+
+macro_rules! impl_cgmath_vector {
+    ($VecN:ident, $Vector:ident; $($field:ident),+) => {
+        impl<T> From<::cgmath::$Vector<T>> for $VecN<T> {
+            #[inline]
+            fn from(v: ::cgmath::$Vector<T>) -> Self {
+                $VecN::new($(v.$field),+)
+            }
+        }
+
+        impl<T> From<$VecN<T>> for ::cgmath::$Vector<T> {
+            #[inline]
+            fn from(v: $VecN<T>) -> Self {
+                ::cgmath::$Vector { $($field: v.$field),+ }
+            }
+        }
+    };
+    ($VecN:ident, $Vector:ident, $Point:ident; $($field:ident),+) => {
+        impl_cgmath_vector!($VecN, $Vector; $($field),+);
+
+        impl<T> From<::cgmath::$Point<T>> for $VecN<T> {
+            #[inline]
+            fn from(p: ::cgmath::$Point<T>) -> Self {
+                $VecN::new($(p.$field),+)
+            }
+        }
+
+        impl<T> From<$VecN<T>> for ::cgmath::$Point<T> {
+            #[inline]
+            fn from(v: $VecN<T>) -> Self {
+                ::cgmath::$Point { $($field: v.$field),+ }
+            }
+        }
+    };
+}
+
+impl_cgmath_vector!(Vec2, Vector2, Point2; x, y);
+impl_cgmath_vector!(Vec3, Vector3, Point3; x, y, z);
+impl_cgmath_vector!(Vec4, Vector4; x, y, z, w);
+
+//cgmath matrices are structs of their columns
+macro_rules! impl_cgmath_matrix {
+    ($MatN:ident, $VecN:ident, $Matrix:ident, $($column:ident: $i:expr),+) => {
+        impl<T> From<::cgmath::$Matrix<T>> for $MatN<T> {
+            #[inline]
+            fn from(m: ::cgmath::$Matrix<T>) -> Self {
+                $MatN::from(($($VecN::from(m.$column)),+))
+            }
+        }
+
+        impl<T: Copy> From<$MatN<T>> for ::cgmath::$Matrix<T> {
+            #[inline]
+            fn from(m: $MatN<T>) -> Self {
+                ::cgmath::$Matrix {
+                    $($column: m[$i].into()),+
+                }
+            }
+        }
+    };
+}
+
+impl_cgmath_matrix!(Mat2, Vec2, Matrix2, x: 0, y: 1);
+impl_cgmath_matrix!(Mat3, Vec3, Matrix3, x: 0, y: 1, z: 2);
+impl_cgmath_matrix!(Mat4, Vec4, Matrix4, x: 0, y: 1, z: 2, w: 3);
+
+impl<T: FloatScalar> From<::cgmath::Quaternion<T>> for Quaternion<T> {
+    #[inline]
+    fn from(q: ::cgmath::Quaternion<T>) -> Self {
+        Quaternion::new_sv(q.s, Vec3::from(q.v))
+    }
+}
+
+impl<T: FloatScalar> From<Quaternion<T>> for ::cgmath::Quaternion<T> {
+    #[inline]
+    fn from(q: Quaternion<T>) -> Self {
+        ::cgmath::Quaternion {
+            v: q.v.into(),
+            s: q.w,
+        }
+    }
+}
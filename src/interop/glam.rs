@@ -0,0 +1,90 @@
+use crate::matrix::{Mat2, Mat3, Mat4};
+use crate::quaternions::Quaternion;
+use crate::vectors::{Vec2, Vec3, Vec4};
+
+macro_rules! impl_glam_vector {
+    ($VecN:ident<$T:ty>, $Glam:ident, $($field:ident),+) => {
+        impl From<::glam::$Glam> for $VecN<$T> {
+            #[inline]
+            fn from(v: ::glam::$Glam) -> Self {
+                let [$($field),+] = v.to_array();
+                $VecN::new($($field),+)
+            }
+        }
+
+        impl From<$VecN<$T>> for ::glam::$Glam {
+            #[inline]
+            fn from(v: $VecN<$T>) -> Self {
+                ::glam::$Glam::new($(v.$field),+)
+            }
+        }
+    };
+}
+
+impl_glam_vector!(Vec2<f32>, Vec2, x, y);
+impl_glam_vector!(Vec3<f32>, Vec3, x, y, z);
+impl_glam_vector!(Vec3<f32>, Vec3A, x, y, z);
+impl_glam_vector!(Vec4<f32>, Vec4, x, y, z, w);
+impl_glam_vector!(Vec2<f64>, DVec2, x, y);
+impl_glam_vector!(Vec3<f64>, DVec3, x, y, z);
+impl_glam_vector!(Vec4<f64>, DVec4, x, y, z, w);
+impl_glam_vector!(Vec2<i32>, IVec2, x, y);
+impl_glam_vector!(Vec3<i32>, IVec3, x, y, z);
+impl_glam_vector!(Vec4<i32>, IVec4, x, y, z, w);
+impl_glam_vector!(Vec2<u32>, UVec2, x, y);
+impl_glam_vector!(Vec3<u32>, UVec3, x, y, z);
+impl_glam_vector!(Vec4<u32>, UVec4, x, y, z, w);
+
+//both sides are arrays of columns
+macro_rules! impl_glam_matrix {
+    ($MatN:ident<$T:ty>, $Glam:ident, $n:expr) => {
+        impl From<::glam::$Glam> for $MatN<$T> {
+            #[inline]
+            fn from(m: ::glam::$Glam) -> Self {
+                $MatN::from(m.to_cols_array_2d())
+            }
+        }
+
+        impl From<$MatN<$T>> for ::glam::$Glam {
+            #[inline]
+            fn from(m: $MatN<$T>) -> Self {
+                let mut columns = [[0.0; $n]; $n];
+                for (i, column) in columns.iter_mut().enumerate() {
+                    *column = m[i];
+                }
+                ::glam::$Glam::from_cols_array_2d(&columns)
+            }
+        }
+    };
+}
+
+impl_glam_matrix!(Mat2<f32>, Mat2, 2);
+impl_glam_matrix!(Mat3<f32>, Mat3, 3);
+impl_glam_matrix!(Mat3<f32>, Mat3A, 3);
+impl_glam_matrix!(Mat4<f32>, Mat4, 4);
+impl_glam_matrix!(Mat2<f64>, DMat2, 2);
+impl_glam_matrix!(Mat3<f64>, DMat3, 3);
+impl_glam_matrix!(Mat4<f64>, DMat4, 4);
+
+//glam stores the scalar part last
+macro_rules! impl_glam_quaternion {
+    ($T:ty, $Glam:ident) => {
+        impl From<::glam::$Glam> for Quaternion<$T> {
+            #[inline]
+            fn from(q: ::glam::$Glam) -> Self {
+                let [x, y, z, w] = q.to_array();
+                Quaternion::new_sv(w, Vec3::new(x, y, z))
+            }
+        }
+
+        impl From<Quaternion<$T>> for ::glam::$Glam {
+            #[inline]
+            fn from(q: Quaternion<$T>) -> Self {
+                ::glam::$Glam::from_xyzw(q.v.x, q.v.y, q.v.z, q.w)
+            }
+        }
+    };
+}
+
+impl_glam_quaternion!(f32, Quat);
+impl_glam_quaternion!(f64, DQuat);
@@ -0,0 +1,114 @@
+use ::mint::IntoMint;
+
+use crate::matrix::{Mat2, Mat3, Mat4};
+use crate::quaternions::Quaternion;
+use crate::vectors::{Vec2, Vec3, Vec4};
+use crate::FloatScalar;
+
+macro_rules! impl_mint_vector {
+    ($VecN:ident, $Vector:ident; $($field:ident),+) => {
+        impl<T> From<::mint::$Vector<T>> for $VecN<T> {
+            #[inline]
+            fn from(v: ::mint::$Vector<T>) -> Self {
+                $VecN::new($(v.$field),+)
+            }
+        }
+
+        impl<T> From<$VecN<T>> for ::mint::$Vector<T> {
+            #[inline]
+            fn from(v: $VecN<T>) -> Self {
+                ::mint::$Vector { $($field: v.$field),+ }
+            }
+        }
+
+        impl<T> IntoMint for $VecN<T> {
+            type MintType = ::mint::$Vector<T>;
+        }
+    };
+    ($VecN:ident, $Vector:ident, $Point:ident; $($field:ident),+) => {
+        impl_mint_vector!($VecN, $Vector; $($field),+);
+
+        impl<T> From<::mint::$Point<T>> for $VecN<T> {
+            #[inline]
+            fn from(p: ::mint::$Point<T>) -> Self {
+                $VecN::new($(p.$field),+)
+            }
+        }
+
+        impl<T> From<$VecN<T>> for ::mint::$Point<T> {
+            #[inline]
+            fn from(v: $VecN<T>) -> Self {
+                ::mint::$Point { $($field: v.$field),+ }
+            }
+        }
+    };
+}
+
+impl_mint_vector!(Vec2, Vector2, Point2; x, y);
+impl_mint_vector!(Vec3, Vector3, Point3; x, y, z);
+impl_mint_vector!(Vec4, Vector4; x, y, z, w);
+
+macro_rules! impl_mint_matrix {
+    ($MatN:ident, $ColumnMatrix:ident, $RowMatrix:ident, $n:expr) => {
+        impl<T> From<::mint::$ColumnMatrix<T>> for $MatN<T> {
+            #[inline]
+            fn from(m: ::mint::$ColumnMatrix<T>) -> Self {
+                $MatN::from(<[[T; $n]; $n]>::from(m))
+            }
+        }
+
+        impl<T: Copy> From<$MatN<T>> for ::mint::$ColumnMatrix<T> {
+            #[inline]
+            fn from(m: $MatN<T>) -> Self {
+                let mut columns = [m[0]; $n];
+                for (i, column) in columns.iter_mut().enumerate() {
+                    *column = m[i];
+                }
+                ::mint::$ColumnMatrix::from(columns)
+            }
+        }
+
+        impl<T> From<::mint::$RowMatrix<T>> for $MatN<T> {
+            #[inline]
+            fn from(m: ::mint::$RowMatrix<T>) -> Self {
+                $MatN::from(::mint::$ColumnMatrix::from(m))
+            }
+        }
+
+        impl<T: Copy> From<$MatN<T>> for ::mint::$RowMatrix<T> {
+            #[inline]
+            fn from(m: $MatN<T>) -> Self {
+                ::mint::$RowMatrix::from(::mint::$ColumnMatrix::from(m))
+            }
+        }
+
+        impl<T: Copy> IntoMint for $MatN<T> {
+            type MintType = ::mint::$ColumnMatrix<T>;
+        }
+    };
+}
+
+impl_mint_matrix!(Mat2, ColumnMatrix2, RowMatrix2, 2);
+impl_mint_matrix!(Mat3, ColumnMatrix3, RowMatrix3, 3);
+impl_mint_matrix!(Mat4, ColumnMatrix4, RowMatrix4, 4);
+
+impl<T: FloatScalar> From<::mint::Quaternion<T>> for Quaternion<T> {
+    #[inline]
+    fn from(q: ::mint::Quaternion<T>) -> Self {
+        Quaternion::new_sv(q.s, Vec3::from(q.v))
+    }
+}
+
+impl<T: FloatScalar> From<Quaternion<T>> for ::mint::Quaternion<T> {
+    #[inline]
+    fn from(q: Quaternion<T>) -> Self {
+        ::mint::Quaternion {
+            v: q.v.into(),
+            s: q.w,
+        }
+    }
+}
+
+impl<T: FloatScalar> IntoMint for Quaternion<T> {
+    type MintType = ::mint::Quaternion<T>;
+}
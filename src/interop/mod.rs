@@ -0,0 +1,15 @@
+//! Conversions to and from the types of other math crates, each behind the feature of the same
+//! name.
+//!
+//! Matrices are converted column by column, as every one of these crates stores them column
+//! major. Quaternions keep their meaning but not their field order: ours are `w, v`, while the
+//! other crates take `x, y, z, w` or `v, s`.
+
+#[cfg(feature = "cgmath")]
+mod cgmath;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
@@ -0,0 +1,104 @@
+use ::nalgebra::{RealField, Scalar};
+
+use crate::matrix::{Mat2, Mat3, Mat4};
+use crate::quaternions::Quaternion;
+use crate::vectors::{Vec2, Vec3, Vec4};
+use crate::FloatScalar;
+
+macro_rules! impl_nalgebra_vector {
+    ($VecN:ident, $Vector:ident; $($field:ident: $i:expr),+) => {
+        impl<T: Scalar + Copy> From<::nalgebra::$Vector<T>> for $VecN<T> {
+            #[inline]
+            fn from(v: ::nalgebra::$Vector<T>) -> Self {
+                $VecN::new($(v[$i]),+)
+            }
+        }
+
+        impl<T: Scalar> From<$VecN<T>> for ::nalgebra::$Vector<T> {
+            #[inline]
+            fn from(v: $VecN<T>) -> Self {
+                ::nalgebra::$Vector::new($(v.$field),+)
+            }
+        }
+    };
+    ($VecN:ident, $Vector:ident, $Point:ident; $($field:ident: $i:expr),+) => {
+        impl_nalgebra_vector!($VecN, $Vector; $($field: $i),+);
+
+        impl<T: Scalar + Copy> From<::nalgebra::$Point<T>> for $VecN<T> {
+            #[inline]
+            fn from(p: ::nalgebra::$Point<T>) -> Self {
+                $VecN::from(p.coords)
+            }
+        }
+
+        impl<T: Scalar> From<$VecN<T>> for ::nalgebra::$Point<T> {
+            #[inline]
+            fn from(v: $VecN<T>) -> Self {
+                ::nalgebra::$Point::new($(v.$field),+)
+            }
+        }
+    };
+}
+
+impl_nalgebra_vector!(Vec2, Vector2, Point2; x: 0, y: 1);
+impl_nalgebra_vector!(Vec3, Vector3, Point3; x: 0, y: 1, z: 2);
+impl_nalgebra_vector!(Vec4, Vector4; x: 0, y: 1, z: 2, w: 3);
+
+//nalgebra is indexed by (row, column), we are indexed by [column][row]
+macro_rules! impl_nalgebra_matrix {
+    ($MatN:ident, $Matrix:ident, $n:expr) => {
+        impl<T: Scalar + Copy> From<::nalgebra::$Matrix<T>> for $MatN<T> {
+            #[inline]
+            fn from(m: ::nalgebra::$Matrix<T>) -> Self {
+                let mut columns = [[m[(0, 0)]; $n]; $n];
+                for (j, column) in columns.iter_mut().enumerate() {
+                    for (i, e) in column.iter_mut().enumerate() {
+                        *e = m[(i, j)];
+                    }
+                }
+                $MatN::from(columns)
+            }
+        }
+
+        impl<T: Scalar + Copy> From<$MatN<T>> for ::nalgebra::$Matrix<T> {
+            #[inline]
+            fn from(m: $MatN<T>) -> Self {
+                ::nalgebra::$Matrix::from_fn(|i, j| m[j][i])
+            }
+        }
+    };
+}
+
+impl_nalgebra_matrix!(Mat2, Matrix2, 2);
+impl_nalgebra_matrix!(Mat3, Matrix3, 3);
+impl_nalgebra_matrix!(Mat4, Matrix4, 4);
+
+impl<T: FloatScalar + Scalar> From<::nalgebra::Quaternion<T>> for Quaternion<T> {
+    #[inline]
+    fn from(q: ::nalgebra::Quaternion<T>) -> Self {
+        //the coordinates are stored as (i, j, k, w)
+        Quaternion::new_sv(q.coords[3], Vec3::from(q.coords.xyz()))
+    }
+}
+
+impl<T: FloatScalar> From<Quaternion<T>> for ::nalgebra::Quaternion<T> {
+    #[inline]
+    fn from(q: Quaternion<T>) -> Self {
+        ::nalgebra::Quaternion::new(q.w, q.v.x, q.v.y, q.v.z)
+    }
+}
+
+impl<T: FloatScalar + RealField> From<::nalgebra::UnitQuaternion<T>> for Quaternion<T> {
+    #[inline]
+    fn from(q: ::nalgebra::UnitQuaternion<T>) -> Self {
+        Quaternion::from(q.into_inner())
+    }
+}
+
+///Normalizes `q`
+impl<T: FloatScalar + RealField> From<Quaternion<T>> for ::nalgebra::UnitQuaternion<T> {
+    #[inline]
+    fn from(q: Quaternion<T>) -> Self {
+        ::nalgebra::UnitQuaternion::from_quaternion(q.into())
+    }
+}
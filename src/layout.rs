@@ -0,0 +1,240 @@
+//! GPU buffer support, enabled by the `bytemuck` feature.
+//!
+//! All the `#[repr(C)]` types are `Pod` when their scalar is, so slices of them can be cast to
+//! bytes directly. Buffers read by shaders also follow the std140 (uniform buffers) or std430
+//! (storage buffers) rules, which pad `vec3` to 16 bytes and every `mat3` column to a `vec4`:
+//! `AsStd140`/`AsStd430` give the padded form of each type and `Std140Buffer`/`Std430Buffer` lay
+//! out a whole block.
+
+use bytemuck::{Pod, Zeroable};
+
+use core::mem;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::complex::Complex;
+use crate::euler::Euler;
+use crate::matrix::{Mat2, Mat3, Mat4};
+use crate::quaternions::Quaternion;
+use crate::rotor::{Bivector3, Rotor3};
+use crate::vectors::{Vec2, Vec3, Vec4};
+use crate::FloatScalar;
+
+//every field has the type T, so there is no padding to expose
+macro_rules! impl_pod {
+    ($($Type:ident<T: $bound:path>),+) => {
+        $(
+            unsafe impl<T: Zeroable + $bound> Zeroable for $Type<T> {}
+            unsafe impl<T: Pod + $bound> Pod for $Type<T> {}
+        )+
+    };
+}
+
+impl_pod!(
+    Vec2<T: Copy>,
+    Vec3<T: Copy>,
+    Vec4<T: Copy>,
+    Mat2<T: Copy>,
+    Mat3<T: Copy>,
+    Mat4<T: Copy>,
+    Quaternion<T: FloatScalar>,
+    Euler<T: FloatScalar>,
+    Complex<T: Copy>,
+    Bivector3<T: Copy>,
+    Rotor3<T: Copy>
+);
+
+///A type with a std140 layout, the one of uniform buffers
+pub trait AsStd140 {
+    ///The value with its padding, as stored in an array
+    type Output: Pod;
+    ///Base alignment in bytes
+    const ALIGN: usize;
+    ///Bytes taken before the next member can start, smaller than `Output` for a `vec3`
+    const SIZE: usize;
+
+    fn as_std140(&self) -> Self::Output;
+}
+
+///A type with a std430 layout, the one of storage buffers and push constants
+pub trait AsStd430 {
+    ///The value with its padding, as stored in an array
+    type Output: Pod;
+    ///Base alignment in bytes
+    const ALIGN: usize;
+    ///Bytes taken before the next member can start, smaller than `Output` for a `vec3`
+    const SIZE: usize;
+
+    fn as_std430(&self) -> Self::Output;
+}
+
+//both layouts only differ on arrays and matrix columns, where std140 rounds up to a vec4
+macro_rules! impl_layout_vectors {
+    ($($T:ty),+) => {
+        $(
+            impl_layout_vectors!(@impl AsStd140, as_std140, $T);
+            impl_layout_vectors!(@impl AsStd430, as_std430, $T);
+        )+
+    };
+    (@impl $Trait:ident, $method:ident, $T:ty) => {
+        impl $Trait for $T {
+            type Output = $T;
+            const ALIGN: usize = 4;
+            const SIZE: usize = 4;
+
+            #[inline]
+            fn $method(&self) -> $T {
+                *self
+            }
+        }
+
+        impl $Trait for Vec2<$T> {
+            type Output = [$T; 2];
+            const ALIGN: usize = 8;
+            const SIZE: usize = 8;
+
+            #[inline]
+            fn $method(&self) -> [$T; 2] {
+                [self.x, self.y]
+            }
+        }
+
+        impl $Trait for Vec3<$T> {
+            type Output = [$T; 4];
+            const ALIGN: usize = 16;
+            const SIZE: usize = 12;
+
+            #[inline]
+            fn $method(&self) -> [$T; 4] {
+                [self.x, self.y, self.z, 0 as $T]
+            }
+        }
+
+        impl $Trait for Vec4<$T> {
+            type Output = [$T; 4];
+            const ALIGN: usize = 16;
+            const SIZE: usize = 16;
+
+            #[inline]
+            fn $method(&self) -> [$T; 4] {
+                [self.x, self.y, self.z, self.w]
+            }
+        }
+    };
+}
+
+impl_layout_vectors!(f32, i32, u32);
+
+//matrices are arrays of their columns
+macro_rules! impl_layout_matrix {
+    ($Trait:ident, $method:ident, $MatN:ident, $n:expr, $rows:expr) => {
+        impl $Trait for $MatN<f32> {
+            type Output = [[f32; $rows]; $n];
+            const ALIGN: usize = 4 * $rows;
+            const SIZE: usize = 4 * $rows * $n;
+
+            #[inline]
+            fn $method(&self) -> Self::Output {
+                let mut ret = [[0.0; $rows]; $n];
+                for (i, column) in ret.iter_mut().enumerate() {
+                    column[..$n].copy_from_slice(&self[i]);
+                }
+                ret
+            }
+        }
+    };
+}
+
+impl_layout_matrix!(AsStd140, as_std140, Mat2, 2, 4);
+impl_layout_matrix!(AsStd140, as_std140, Mat3, 3, 4);
+impl_layout_matrix!(AsStd140, as_std140, Mat4, 4, 4);
+impl_layout_matrix!(AsStd430, as_std430, Mat2, 2, 2);
+impl_layout_matrix!(AsStd430, as_std430, Mat3, 3, 4);
+impl_layout_matrix!(AsStd430, as_std430, Mat4, 4, 4);
+
+#[cfg(feature = "alloc")]
+macro_rules! impl_buffer {
+    ($(#[$doc:meta])* $Buffer:ident, $Trait:ident, $method:ident, $round:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Default)]
+        pub struct $Buffer {
+            bytes: Vec<u8>,
+            len: usize,
+            align: usize,
+        }
+
+        impl $Buffer {
+            #[inline]
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            ///Appends `value` at its next aligned offset, which gets returned
+            pub fn push<V: $Trait>(&mut self, value: &V) -> usize {
+                let offset = self.len.next_multiple_of(V::ALIGN);
+                self.write(offset, bytemuck::bytes_of(&value.$method()));
+                self.len = offset + V::SIZE;
+                self.align = self.align.max(V::ALIGN);
+                offset
+            }
+
+            ///Appends `values` as an array, returning its offset
+            pub fn push_array<V: $Trait>(&mut self, values: &[V]) -> usize {
+                let align = V::ALIGN.max($round);
+                let stride = mem::size_of::<V::Output>().next_multiple_of(align);
+                let offset = self.len.next_multiple_of(align);
+
+                for (i, value) in values.iter().enumerate() {
+                    self.write(offset + i * stride, bytemuck::bytes_of(&value.$method()));
+                }
+                self.len = offset + stride * values.len();
+                self.align = self.align.max(align);
+                offset
+            }
+
+            ///The offset where the next member would start, before its alignment
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.len
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.len == 0
+            }
+
+            ///The bytes of the block, padded to the alignment of its largest member
+            pub fn into_bytes(mut self) -> Vec<u8> {
+                let end = self.len.next_multiple_of(self.align.max($round));
+                self.bytes.resize(end, 0);
+                self.bytes
+            }
+
+            fn write(&mut self, offset: usize, data: &[u8]) {
+                let end = offset + data.len();
+                if self.bytes.len() < end {
+                    self.bytes.resize(end, 0);
+                }
+                self.bytes[offset..end].copy_from_slice(data);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+impl_buffer!(
+    ///Lays out a uniform block member by member with the std140 rules
+    Std140Buffer,
+    AsStd140,
+    as_std140,
+    16
+);
+#[cfg(feature = "alloc")]
+impl_buffer!(
+    ///Lays out a storage block member by member with the std430 rules
+    Std430Buffer,
+    AsStd430,
+    as_std430,
+    1
+);
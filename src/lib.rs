@@ -1,34 +1,90 @@
 #![crate_type = "lib"]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(unused_imports)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 extern crate num_traits;
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("the float math needs either the `std` or the `libm` feature");
+
+mod alignment;
+mod batch;
+mod bvh;
+mod collision;
+mod complex;
+mod display;
+mod dual;
 mod euler;
+mod fitting;
+mod interop;
+#[cfg(feature = "bytemuck")]
+mod layout;
+mod lie;
 mod matrix;
 mod numtrait;
+mod precision;
 mod quaternions;
+mod rotor;
+#[cfg(feature = "serde")]
+mod serialization;
+#[cfg(feature = "simd")]
+mod simd;
 mod vectors;
 
+pub use alignment::{kabsch, umeyama, Alignment};
+#[cfg(feature = "alloc")]
+pub use bvh::Bvh;
+pub use bvh::{Aabb, Ray};
+#[cfg(feature = "alloc")]
+pub use collision::{epa_penetration, ConvexHull};
+pub use collision::{
+    gjk_closest_points, gjk_distance, gjk_intersects, Capsule, Cuboid, Sphere, SupportMap,
+    Transformed,
+};
+pub use complex::Complex;
+pub use dual::{jacobian, Dual, DualN};
 pub use euler::Euler;
+pub use fitting::{centroid, covariance, fit_line, fit_plane, pca, Pca};
+#[cfg(feature = "bytemuck")]
+pub use layout::{AsStd140, AsStd430};
+#[cfg(all(feature = "bytemuck", feature = "alloc"))]
+pub use layout::{Std140Buffer, Std430Buffer};
+pub use lie::{se3, so3, SE3, SO3};
+pub use matrix::orthonormalize;
 pub use matrix::Mat2;
 pub use matrix::Mat3;
 pub use matrix::Mat4;
-pub use numtrait::{FloatScalar, RealScalar};
+pub use numtrait::{BatchScalar, FloatScalar, RealScalar, Scalar, SimdScalar};
 pub use quaternions::Quaternion;
+pub use rotor::{Bivector3, Rotor2, Rotor3};
+#[cfg(feature = "serde")]
+pub use serialization::named;
 pub use vectors::Vec2;
 pub use vectors::Vec3;
 pub use vectors::Vec4;
+pub use vectors::{BVec2, BVec3, BVec4};
+pub use vectors::{Vec3x4, Vec3x8};
 
 #[macro_export]
 macro_rules! abs_diff_eq {
     ($lhs:expr, $rhs:expr) => {
-        ($lhs - $rhs) < std::f32::EPSILON
+        ($lhs - $rhs) < f32::EPSILON
     };
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use num_traits::Float;
+
+    //`abs_diff_eq!` with an explicit tolerance, comparing the absolute difference
+    macro_rules! near {
+        ($lhs:expr, $rhs:expr, $eps:expr) => {
+            ($lhs - $rhs).abs() < $eps
+        };
+    }
 
     #[test]
     fn vec_index() {
@@ -153,6 +209,7 @@ mod tests {
 
     #[test]
     #[should_panic]
+    #[allow(clippy::no_effect)]
     fn mat3_index_fail() {
         let mat: Mat3<f32> = Mat3::default();
 
@@ -256,6 +313,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::float_equality_without_abs)]
     fn mat3_rotation() {
         let mat = Mat3::rotation_z(-180.0_f32.to_radians());
         let vec = Vec3::new(0.0, 1.0, 0.0);
@@ -268,6 +326,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::float_equality_without_abs)]
     fn quaternion_rotation() {
         let quat = Quaternion::new(-90.0_f32.to_radians(), Vec3::new(0.0, 0.0, 1.0));
         let mat = Mat4::from(quat);
@@ -280,4 +339,1925 @@ mod tests {
         assert!(abs_diff_eq!(mul.z, res.z));
         assert!(abs_diff_eq!(mul.w, res.w));
     }
+
+    //two triangles per unit cell of a `size` x `size` grid lying on the z = height plane
+    #[cfg(feature = "alloc")]
+    fn triangle_grid(size: usize, height: f32) -> Vec<[Vec3<f32>; 3]> {
+        let mut tris = Vec::new();
+        for i in 0..size {
+            for j in 0..size {
+                let (x, y) = (i as f32, j as f32);
+                let a = Vec3::new(x, y, height);
+                let b = Vec3::new(x + 1.0, y, height);
+                let c = Vec3::new(x, y + 1.0, height);
+                let d = Vec3::new(x + 1.0, y + 1.0, height);
+                tris.push([a, b, c]);
+                tris.push([b, d, c]);
+            }
+        }
+        tris
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bvh_ray_cast() {
+        let mut tris = triangle_grid(8, 0.0);
+        tris.extend(triangle_grid(8, 2.0));
+        let bounds: Vec<Aabb<f32>> = tris.iter().map(|t| Aabb::from_points(t)).collect();
+        let bvh = Bvh::build(&bounds);
+
+        let ray = Ray::new(Vec3::new(3.3, 4.6, 5.0), Vec3::new(0.0, 0.0, -1.0));
+        let (index, t) = bvh
+            .ray_cast(&ray, f32::INFINITY, |i, r| {
+                r.intersect_triangle(tris[i][0], tris[i][1], tris[i][2])
+            })
+            .unwrap();
+
+        let brute = tris
+            .iter()
+            .enumerate()
+            .filter_map(|(i, tri)| {
+                ray.intersect_triangle(tri[0], tri[1], tri[2])
+                    .map(|t| (i, t))
+            })
+            .fold(None, |best: Option<(usize, f32)>, hit| match best {
+                Some(b) if b.1 <= hit.1 => Some(b),
+                _ => Some(hit),
+            })
+            .unwrap();
+
+        assert_eq!(index, brute.0);
+        assert!(near!(t, 3.0, 1e-5));
+        assert!(bvh
+            .ray_cast(&ray, 2.5, |i, r| r
+                .intersect_triangle(tris[i][0], tris[i][1], tris[i][2]))
+            .is_none());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bvh_nearest_and_overlap() {
+        let points: Vec<Vec3<f32>> = (0..100)
+            .map(|i| Vec3::new((i % 10) as f32, (i / 10) as f32, (i % 7) as f32))
+            .collect();
+        let bounds: Vec<Aabb<f32>> = points.iter().map(|&p| Aabb::new(p, p)).collect();
+        let bvh = Bvh::build(&bounds);
+
+        let query = Vec3::new(4.2, 6.9, 3.1);
+        let (index, _) = bvh.nearest(query, |i, _| points[i]).unwrap();
+        let brute = (0..points.len())
+            .min_by(|&a, &b| {
+                let da = points[a].distance_to(query);
+                let db = points[b].distance_to(query);
+                da.partial_cmp(&db).unwrap()
+            })
+            .unwrap();
+        assert_eq!(index, brute);
+
+        let region = Aabb::new(Vec3::new(1.5, 1.5, 0.0), Vec3::new(3.5, 2.5, 10.0));
+        let mut found = bvh.overlapping(&region);
+        found.sort();
+        let expected: Vec<usize> = (0..points.len())
+            .filter(|&i| region.contains(points[i]))
+            .collect();
+        assert_eq!(found, expected);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bvh_refit() {
+        let tris = triangle_grid(4, 0.0);
+        let bounds: Vec<Aabb<f32>> = tris.iter().map(|t| Aabb::from_points(t)).collect();
+        let mut bvh = Bvh::build(&bounds);
+
+        let offset = Vec3::new(0.0, 0.0, 10.0);
+        let moved: Vec<Aabb<f32>> = bounds
+            .iter()
+            .map(|b| Aabb::new(b.min + offset, b.max + offset))
+            .collect();
+        bvh.refit(&moved);
+
+        assert_eq!(bvh.bounds().min.z, 10.0);
+        assert_eq!(bvh.bounds().max.z, 10.0);
+
+        let ray = Ray::new(Vec3::new(1.2, 1.7, 20.0), Vec3::new(0.0, 0.0, -1.0));
+        let hit = bvh.ray_cast(&ray, f32::INFINITY, |i, r| {
+            let t = tris[i];
+            r.intersect_triangle(t[0] + offset, t[1] + offset, t[2] + offset)
+        });
+        assert!(near!(hit.unwrap().1, 10.0, 1e-5));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bvh_skewed_input() {
+        //every split only peels off the farthest few boxes, so the tree is about as deep as
+        //there are boxes. Built on a small stack to catch a recursive build
+        let build = std::thread::Builder::new()
+            .stack_size(64 * 1024)
+            .spawn(|| {
+                let bounds: Vec<Aabb<f64>> = (0..1500)
+                    .map(|i| {
+                        let p = Vec3::new(1.2.powi(i), 0.0, 0.0);
+                        Aabb::new(p, p + Vec3::new(1.0, 1.0, 1.0))
+                    })
+                    .collect();
+                (Bvh::build(&bounds), bounds)
+            })
+            .unwrap();
+        let (bvh, bounds) = build.join().unwrap();
+
+        assert_eq!(bvh.len(), 1500);
+        for &i in &[0, 700, 1499] {
+            let query = bounds[i].center();
+            let (index, _) = bvh.nearest(query, |p, _| bounds[p].center()).unwrap();
+            assert_eq!(index, i);
+        }
+    }
+
+    #[test]
+    fn gjk_sphere_distance() {
+        let a = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0);
+        let b = Sphere::new(Vec3::new(3.0, 4.0, 0.0), 2.0);
+
+        assert!(near!(gjk_distance(&a, &b).unwrap(), 2.0_f64, 1e-6));
+
+        let (pa, pb) = gjk_closest_points(&a, &b).unwrap();
+        assert!(near!(pa.distance_to(Vec3::new(0.6, 0.8, 0.0)), 0.0, 1e-6));
+        assert!(near!(pb.distance_to(Vec3::new(1.8, 2.4, 0.0)), 0.0, 1e-6));
+
+        let c = Sphere::new(Vec3::new(2.5, 0.0, 0.0), 2.0);
+        assert!(gjk_intersects(&a, &c));
+        assert!(gjk_distance(&a, &c).is_none());
+    }
+
+    #[test]
+    fn gjk_box_capsule_hull() {
+        let cube = Cuboid::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+        let capsule = Capsule::new(Vec3::new(3.0, -5.0, 0.0), Vec3::new(3.0, 5.0, 0.0), 0.5);
+        assert!(near!(gjk_distance(&cube, &capsule).unwrap(), 1.5_f64, 1e-6));
+
+        #[cfg(feature = "alloc")]
+        {
+            let hull = ConvexHull::new(vec![
+                Vec3::new(0.0, 0.0, 4.0),
+                Vec3::new(1.0, 0.0, 4.0),
+                Vec3::new(0.0, 1.0, 4.0),
+                Vec3::new(0.2, 0.2, 5.0),
+            ])
+            .unwrap();
+            assert!(near!(gjk_distance(&cube, &hull).unwrap(), 3.0, 1e-6));
+            assert!(ConvexHull::<f64>::new(Vec::new()).is_none());
+        }
+
+        let rotated = Transformed::new(
+            cube,
+            Mat3::rotation_z(45.0_f64.to_radians()),
+            Vec3::new(1.0 + 2.0_f64.sqrt() + 0.5, 0.0, 0.0),
+        );
+        assert!(near!(gjk_distance(&cube, &rotated).unwrap(), 0.5, 1e-6));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn epa_depth() {
+        let a = Cuboid::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 1.0));
+        let b = Cuboid::new(Vec3::new(1.7, 0.2, -0.1), Vec3::new(1.0, 1.0, 1.0));
+        let pen = epa_penetration(&a, &b).unwrap();
+        assert!(near!(pen.x, 0.3_f64, 1e-6));
+        assert!(near!(pen.y, 0.0, 1e-6));
+        assert!(near!(pen.z, 0.0, 1e-6));
+
+        let s1 = Sphere::new(Vec3::new(0.0, 0.0, 0.0), 1.0);
+        let s2 = Sphere::new(Vec3::new(0.0, 1.5, 0.0), 1.0);
+        let pen = epa_penetration(&s1, &s2).unwrap();
+        assert!(near!(pen.magnitude(), 0.5_f64, 1e-3));
+        assert!(pen.y > 0.0);
+
+        let far = Sphere::new(Vec3::new(0.0, 5.0, 0.0), 1.0);
+        assert!(epa_penetration(&s1, &far).is_none());
+    }
+
+    #[test]
+    fn mat3_transpose_values() {
+        let mat = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0);
+        let trans = Mat3::new(1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0);
+
+        assert_eq!(mat.transpost(), trans);
+    }
+
+    #[test]
+    fn mat3_svd() {
+        let mat = Mat3::new(2.0, -1.0, 0.5, 0.3, 4.0, 1.0, -2.0, 0.7, 1.5_f64);
+        let (u, s, v) = mat.svd();
+        let sigma = Mat3::new(s.x, 0.0, 0.0, 0.0, s.y, 0.0, 0.0, 0.0, s.z);
+        let rebuilt = u * sigma * v.transpost();
+
+        assert!(s.x >= s.y && s.y >= s.z);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(near!(rebuilt[i][j], mat[i][j], 1e-10));
+            }
+        }
+
+        //NaNs propagate instead of panicking in the sort
+        let nan = Mat3::new(f64::NAN, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+        let (_, s, _) = nan.svd();
+        assert!(s.x.is_nan() || s.y.is_nan() || s.z.is_nan());
+        let (values, _) = nan.symmetric_eigen();
+        assert!(values.x.is_nan() || values.y.is_nan() || values.z.is_nan());
+    }
+
+    fn sample_cloud() -> Vec<Vec3<f64>> {
+        vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.2, -0.3),
+            Vec3::new(0.4, 2.0, 0.1),
+            Vec3::new(-0.5, 0.3, 1.7),
+            Vec3::new(1.2, -1.1, 0.6),
+        ]
+    }
+
+    #[test]
+    fn kabsch_umeyama_recover() {
+        let src = sample_cloud();
+        let rotation = Mat3::rotation(0.8, Vec3::new(1.0, -2.0, 0.5));
+        let translation = Vec3::new(3.0, -1.0, 2.0);
+
+        let dst: Vec<Vec3<f64>> = src.iter().map(|&p| rotation * p + translation).collect();
+        let fit = kabsch(&src, &dst).unwrap();
+        assert!(fit.rmsd < 1e-10);
+        assert!(near!(fit.rotation.determinant(), 1.0, 1e-10));
+        assert!(fit.translation.distance_to(translation) < 1e-10);
+
+        let scaled: Vec<Vec3<f64>> = src
+            .iter()
+            .map(|&p| rotation * p * 2.5 + translation)
+            .collect();
+        let fit = umeyama(&src, &scaled).unwrap();
+        assert!(near!(fit.scale, 2.5, 1e-10));
+        assert!(fit.rmsd < 1e-10);
+        assert!(fit.transform_point(src[3]).distance_to(scaled[3]) < 1e-10);
+
+        assert!(kabsch(&src, &dst[1..]).is_none());
+    }
+
+    #[test]
+    fn kabsch_no_reflection() {
+        let src = sample_cloud();
+        let mirrored: Vec<Vec3<f64>> = src.iter().map(|p| Vec3::new(-p.x, p.y, p.z)).collect();
+        let fit = kabsch(&src, &mirrored).unwrap();
+
+        assert!(near!(fit.rotation.determinant(), 1.0, 1e-10));
+        assert!(fit.rmsd > 0.1);
+    }
+
+    #[test]
+    fn mat3_2d_transforms() {
+        let p = Vec2::new(1.0_f64, 0.0);
+        let mat = Mat3::translation_2d(Vec2::new(2.0, 3.0))
+            * Mat3::rotation_2d(90.0_f64.to_radians())
+            * Mat3::scale_2d(Vec2::new(2.0, 1.0));
+        let res = mat.transform_point2(p);
+
+        assert!(near!(res.x, 2.0, 1e-12));
+        assert!(near!(res.y, 5.0, 1e-12));
+    }
+
+    #[test]
+    fn homography_quad() {
+        let src = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ];
+        let dst = [
+            Vec2::new(10.0, 20.0),
+            Vec2::new(30.0, 22.0),
+            Vec2::new(28.0, 45.0),
+            Vec2::new(12.0, 40.0_f64),
+        ];
+        let h = Mat3::homography_from_points(src, dst).unwrap();
+
+        for i in 0..4 {
+            let mapped = h.transform_point2(src[i]);
+            assert!(near!(mapped.x, dst[i].x, 1e-9));
+            assert!(near!(mapped.y, dst[i].y, 1e-9));
+        }
+
+        let collinear = [src[0], src[1], Vec2::new(2.0, 0.0), src[3]];
+        assert!(Mat3::homography_from_points(collinear, dst).is_none());
+    }
+
+    #[test]
+    fn homography_dlt() {
+        let truth = Mat3::new(1.2, 0.1, 0.001, -0.2, 0.9, 0.002, 5.0, -3.0, 1.0_f64);
+        let src: Vec<Vec2<f64>> = (0..12)
+            .map(|i| Vec2::new((i % 4) as f64 * 10.0 + 1.0, (i / 4) as f64 * 7.0 - 3.0))
+            .collect();
+        let dst: Vec<Vec2<f64>> = src.iter().map(|&p| truth.transform_point2(p)).collect();
+
+        let h = Mat3::homography_dlt(&src, &dst).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(near!(h[i][j], truth[i][j], 1e-8));
+            }
+        }
+        assert!(Mat3::homography_dlt(&src[..3], &dst[..3]).is_none());
+    }
+
+    #[test]
+    fn mat3_symmetric_eigen() {
+        let mat = Mat3::new(4.0, 1.0, -2.0, 1.0, 2.0, 0.5, -2.0, 0.5, 3.0_f64);
+        let (values, vectors) = mat.symmetric_eigen();
+
+        assert!(values.x >= values.y && values.y >= values.z);
+        for i in 0..3 {
+            let v = Vec3::from(vectors[i]);
+            let mv = mat * v;
+            assert!(near!(v.magnitude(), 1.0, 1e-12));
+            assert!(mv.distance_to(v * values[i]) < 1e-10);
+        }
+    }
+
+    #[test]
+    fn fit_plane_and_line() {
+        let normal = Vec3::new(1.0, -2.0, 3.0_f64).normalized();
+        let u = normal.cross(Vec3::new(0.0, 0.0, 1.0)).normalized();
+        let v = normal.cross(u);
+        let origin = Vec3::new(1.0, 2.0, 3.0);
+
+        let plane: Vec<Vec3<f64>> = (0..20)
+            .map(|i| origin + u * (i % 5) as f64 + v * (i / 5) as f64 * 0.7)
+            .collect();
+        let (point, n) = fit_plane(&plane).unwrap();
+        assert!(near!(n.dot(normal).abs(), 1.0, 1e-10));
+        assert!(near!((point - origin).dot(normal), 0.0, 1e-10));
+
+        let line: Vec<Vec3<f64>> = (0..10).map(|i| origin + u * i as f64 * 0.3).collect();
+        let (point, dir) = fit_line(&line).unwrap();
+        assert!(near!(dir.dot(u).abs(), 1.0, 1e-10));
+        assert!((point - origin).cross(u).magnitude() < 1e-10);
+
+        assert!(fit_plane::<f64>(&[]).is_none());
+    }
+
+    #[test]
+    fn pca_covariance() {
+        let points = vec![
+            Vec3::new(-2.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(0.0, -1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0_f64),
+        ];
+        let cov = covariance(&points).unwrap();
+        assert_eq!(cov, Mat3::new(2.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.0, 0.0));
+
+        let result = pca(&points).unwrap();
+        assert_eq!(result.centroid, Vec3::new(0.0, 0.0, 0.0));
+        assert!(near!(result.variances.x, 2.0, 1e-12));
+        assert!(near!(result.variances.y, 0.5, 1e-12));
+        assert!(near!(result.major_axis().x.abs(), 1.0, 1e-12));
+        assert!(near!(result.axes.determinant(), 1.0, 1e-12));
+    }
+
+    #[test]
+    fn vec_swizzle() {
+        let v2 = Vec2::new(1.0, 2.0);
+        let v3 = Vec3::new(1.0, 2.0, 3.0);
+        let v4 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_eq!(v2.yx(), Vec2::new(2.0, 1.0));
+        assert_eq!(v2.xyxy(), Vec4::new(1.0, 2.0, 1.0, 2.0));
+        assert_eq!(v3.xzy(), Vec3::new(1.0, 3.0, 2.0));
+        assert_eq!(v3.zz(), Vec2::new(3.0, 3.0));
+        assert_eq!(v4.wzyx(), Vec4::new(4.0, 3.0, 2.0, 1.0));
+        assert_eq!(v4.xy(), Vec2::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn vec_swizzle_set() {
+        let mut v3 = Vec3::new(1.0, 2.0, 3.0);
+        v3.set_zx(Vec2::new(7.0, 8.0));
+        assert_eq!(v3, Vec3::new(8.0, 2.0, 7.0));
+
+        let mut v4 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+        v4.set_wyz(Vec3::new(5.0, 6.0, 7.0));
+        assert_eq!(v4, Vec4::new(1.0, 6.0, 7.0, 5.0));
+    }
+
+    #[test]
+    fn vec_unsigned_ops() {
+        let a: Vec3<u32> = Vec3::new(7, 8, 9);
+        let b = Vec3::new(2, 3, 4);
+
+        assert_eq!(a + b, Vec3::new(9, 11, 13));
+        assert_eq!(a - b, Vec3::new(5, 5, 5));
+        assert_eq!(a * b, Vec3::new(14, 24, 36));
+        assert_eq!(a / b, Vec3::new(3, 2, 2));
+        assert_eq!(a % b, Vec3::new(1, 2, 1));
+        assert_eq!(a % 4, Vec3::new(3, 0, 1));
+        assert_eq!(a.min(b), b);
+        assert_eq!(a.max(b), a);
+
+        let tile: Vec2<usize> = Vec2::new(37, 12);
+        assert_eq!(tile / 8, Vec2::new(4, 1));
+    }
+
+    #[test]
+    fn vec_integer_bit_ops() {
+        let a: Vec4<u8> = Vec4::new(0b1100, 0b1010, 0xff, 1);
+        let b = Vec4::new(0b1010, 0b0110, 0x0f, 1);
+
+        assert_eq!(a & b, Vec4::new(0b1000, 0b0010, 0x0f, 1));
+        assert_eq!(a | b, Vec4::new(0b1110, 0b1110, 0xff, 1));
+        assert_eq!(a ^ b, Vec4::new(0b0110, 0b1100, 0xf0, 0));
+        assert_eq!(!Vec2::new(0u8, 0xf0), Vec2::new(0xff, 0x0f));
+        assert_eq!(Vec2::new(1u32, 3) << 2, Vec2::new(4, 12));
+        assert_eq!(Vec2::new(16i32, -16) >> 2, Vec2::new(4, -4));
+        assert_eq!(Vec3::new(-3i32, 4, -5).abs(), Vec3::new(3, 4, 5));
+    }
+
+    #[test]
+    fn vec_integer_overflow() {
+        let a: Vec2<u8> = Vec2::new(250, 10);
+        let b = Vec2::new(10, 10);
+
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(b.checked_sub(Vec2::new(5, 5)), Some(Vec2::new(5, 5)));
+        assert_eq!(b.checked_div(Vec2::new(0, 1)), None);
+        assert_eq!(a.wrapping_add(b), Vec2::new(4, 20));
+        assert_eq!(a.saturating_add(b), Vec2::new(255, 20));
+        assert_eq!(b.saturating_sub(a), Vec2::new(0, 0));
+    }
+
+    #[test]
+    fn vec_cast() {
+        let grid: Vec3<i32> = Vec3::new(-2, 0, 7);
+        let float: Vec3<f32> = grid.cast().unwrap();
+        assert_eq!(float, Vec3::new(-2.0, 0.0, 7.0));
+
+        let pos = Vec3::new(1.7_f32, -2.5, 300.0);
+        assert_eq!(pos.lossy_cast::<i32>(), Vec3::new(1, -2, 300));
+        assert_eq!(pos.lossy_cast::<u8>(), Vec3::new(1, 0, 255));
+        assert_eq!(pos.cast::<u8>(), None);
+        assert_eq!(pos.cast::<i32>(), Some(Vec3::new(1, -2, 300)));
+    }
+
+    #[test]
+    fn vec_masks() {
+        let a = Vec3::new(1.0, 5.0, 3.0);
+        let b = Vec3::new(2.0, 4.0, 3.0);
+
+        let lt: BVec3 = a.cmplt(&b);
+        assert_eq!(lt, Vec3::new(true, false, false));
+        assert_eq!(a.cmple(&b), Vec3::new(true, false, true));
+        assert_eq!(a.cmpeq(&b) | a.cmpgt(&b), a.cmpge(&b));
+        assert!(lt.any());
+        assert!(!lt.all());
+        assert!((!a.cmpne(&a)).all());
+        assert!(!Vec4::new(false, false, false, false).any());
+
+        //only equality is needed to compare for it
+        let c = Vec2::new(Complex::new(1.0, 2.0), Complex::new(0.0, 1.0));
+        let d = Vec2::new(Complex::new(1.0, 2.0), Complex::new(1.0, 0.0));
+        assert_eq!(c.cmpeq(&d), Vec2::new(true, false));
+        assert_eq!(c.cmpne(&d), Vec2::new(false, true));
+
+        assert_eq!(Vec3::select(lt, a, b), Vec3::new(1.0, 4.0, 3.0));
+        assert_eq!(Vec3::select(lt, a, b), a.min(b));
+    }
+
+    #[test]
+    fn vec_componentwise_math() {
+        let v = Vec4::new(-1.5, 0.25, 2.5, -0.0_f32);
+
+        assert_eq!(v.abs(), Vec4::new(1.5, 0.25, 2.5, 0.0));
+        assert_eq!(v.floor(), Vec4::new(-2.0, 0.0, 2.0, -0.0));
+        assert_eq!(v.ceil(), Vec4::new(-1.0, 1.0, 3.0, -0.0));
+        assert_eq!(v.round(), Vec4::new(-2.0, 0.0, 3.0, -0.0));
+        assert_eq!(v.fract(), Vec4::new(0.5, 0.25, 0.5, 0.0));
+        assert_eq!(Vec2::new(-1e-20, 0.75_f64).fract(), Vec2::new(1.0, 0.75));
+        assert_eq!(Vec2::new(-3.0, 2.0_f64).signum(), Vec2::new(-1.0, 1.0));
+        assert_eq!(Vec3::new(-3, 0, 9).signum(), Vec3::new(-1, 0, 1));
+
+        let lo = Vec2::new(0.0, 0.0);
+        let hi = Vec2::new(1.0, 1.0);
+        assert_eq!(Vec2::new(-0.5, 0.5).clamp(lo, hi), Vec2::new(0.0, 0.5));
+        assert_eq!(
+            Vec2::new(7, -2).clamp(Vec2::new(0, 0), Vec2::new(5, 5)),
+            Vec2::new(5, 0)
+        );
+    }
+
+    #[test]
+    fn vec_reflect_refract() {
+        let n = Vec3::new(0.0, 1.0, 0.0_f64);
+        let i = Vec3::new(1.0, -1.0, 0.0).normalized();
+
+        assert_eq!(i.reflect(n), Vec3::new(i.x, -i.y, 0.0));
+        assert_eq!(
+            Vec2::new(3.0, -4.0).reflect(Vec2::new(0.0, 1.0)),
+            Vec2::new(3.0, 4.0)
+        );
+
+        //same medium, the ray goes straight through
+        let same = i.refract(n, 1.0).unwrap();
+        assert!(near!(same.x, i.x, 1e-12));
+        assert!(near!(same.y, i.y, 1e-12));
+
+        //snell's law: sin(t) = eta * sin(i)
+        let eta = 1.0 / 1.5;
+        let t = i.refract(n, eta).unwrap();
+        assert!(near!(t.magnitude(), 1.0, 1e-12));
+        assert!(near!(t.x, eta * i.x, 1e-12));
+
+        //total internal reflection leaving a dense medium at a grazing angle
+        assert!(i.refract(n, 1.5).is_none());
+    }
+
+    #[test]
+    fn vec_project_angle_slerp() {
+        let a = Vec3::new(2.0, 3.0, -1.0_f64);
+        let b = Vec3::new(1.0, 1.0, 0.0);
+
+        let p = a.project_onto(b);
+        let r = a.reject_from(b);
+        assert_eq!(p, Vec3::new(2.5, 2.5, 0.0));
+        assert!(near!(r.dot(b), 0.0, 1e-12));
+        assert_eq!(p + r, a);
+
+        assert!(near!(
+            Vec2::new(1.0, 0.0).angle_between(Vec2::new(0.0, 2.0_f64)),
+            std::f64::consts::FRAC_PI_2,
+            1e-12
+        ));
+        assert!(near!(b.angle_between(b * 3.0), 0.0, 1e-6));
+        assert!(near!(
+            Vec4::new(1.0, 0.0, 0.0, 0.0).angle_between(Vec4::new(-1.0, 0.0, 0.0, 0.0_f64)),
+            std::f64::consts::PI,
+            1e-12
+        ));
+
+        assert_eq!(a.lerp(b, 0.5), Vec3::new(1.5, 2.0, -0.5));
+
+        let x = Vec3::new(1.0, 0.0, 0.0_f64);
+        let y = Vec3::new(0.0, 1.0, 0.0);
+        let s = x.slerp(y, 1.0 / 3.0);
+        assert!(near!(s.magnitude(), 1.0, 1e-12));
+        assert!(near!(
+            s.angle_between(x),
+            std::f64::consts::FRAC_PI_6,
+            1e-12
+        ));
+        assert_eq!(x.slerp(x, 0.5), x);
+
+        let mid = x.slerp(-x, 0.5);
+        assert!(near!(mid.magnitude(), 1.0, 1e-12));
+        assert!(near!(mid.dot(x), 0.0, 1e-12));
+        assert!(near!((x.slerp(-x, 1.0) + x).magnitude(), 0.0, 1e-12));
+
+        let u = Vec4::new(0.5, -0.5, 0.5, 0.5_f64);
+        let quarter = u.slerp(-u, 0.25);
+        assert!(near!(quarter.magnitude(), 1.0, 1e-12));
+        assert!(near!(
+            quarter.angle_between(u),
+            std::f64::consts::FRAC_PI_4,
+            1e-12
+        ));
+    }
+
+    #[test]
+    fn vec_orthonormal_basis() {
+        let dirs = [
+            Vec3::new(0.0, 0.0, 1.0_f64),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.3, -0.8, 0.5).normalized(),
+            Vec3::new(-0.1, 0.2, -0.97).normalized(),
+        ];
+
+        for &n in dirs.iter() {
+            let (b1, b2) = n.orthonormal_basis();
+            assert!(near!(b1.magnitude(), 1.0, 1e-12));
+            assert!(near!(b2.magnitude(), 1.0, 1e-12));
+            assert!(near!(b1.dot(n), 0.0, 1e-12));
+            assert!(near!(b2.dot(n), 0.0, 1e-12));
+            assert!(near!(b1.dot(b2), 0.0, 1e-12));
+            assert!(near!(b1.cross(b2).dot(n), 1.0, 1e-12));
+
+            let o = (n * 4.0).any_orthogonal();
+            assert!(near!(o.magnitude(), 1.0, 1e-12));
+            assert!(near!(o.dot(n), 0.0, 1e-12));
+        }
+
+        let v = Vec2::new(3.0, 4.0_f64);
+        assert_eq!(v.perp(), Vec2::new(-4.0, 3.0));
+        assert!(near!(v.any_orthogonal().x, -0.8, 1e-12));
+        assert!(near!(v.any_orthogonal().y, 0.6, 1e-12));
+        assert_eq!(v.dot(v), 25.0);
+    }
+
+    #[test]
+    fn mat3_reorthonormalize() {
+        let mut r = Mat3::rotation(0.7, Vec3::new(1.0, 2.0, -0.5_f64).normalized());
+        assert!(r.is_orthonormal(1e-12));
+
+        //simulate the drift of many small incremental updates
+        let step = Mat3::rotation(0.01, Vec3::new(0.0, 0.6, 0.8));
+        let noise = Mat3::new(1.0, 1e-4, 0.0, -2e-4, 1.0, 3e-4, 1e-4, 0.0, 1.0 + 5e-4);
+        for _ in 0..20 {
+            r = noise * step * r;
+        }
+        assert!(!r.is_orthonormal(1e-6));
+
+        let gs = r.orthonormalized();
+        assert!(gs.is_orthonormal(1e-12));
+        assert!(near!(gs.determinant(), 1.0, 1e-12));
+        let x = Vec3::from(r[0]).normalized();
+        assert!(near!(Vec3::from(gs[0]).dot(x), 1.0, 1e-12));
+
+        let polar = r.nearest_rotation();
+        assert!(polar.is_orthonormal(1e-12));
+        assert!(near!(polar.determinant(), 1.0, 1e-12));
+
+        //a rotation is its own nearest rotation
+        let exact = Mat3::rotation_y(1.2_f64);
+        let nearest = exact.nearest_rotation();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(near!(nearest[i][j], exact[i][j], 1e-12));
+            }
+        }
+
+        //degenerate columns are completed into a rotation
+        let flat = Mat3::new(2.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 0.0, 0.0_f64);
+        assert!(flat.orthonormalized().is_orthonormal(1e-12));
+        assert!(near!(flat.orthonormalized().determinant(), 1.0, 1e-12));
+
+        //a dependent middle column keeps the independent ones where they are
+        let middle = Mat3::new(1.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 1.0_f64);
+        let fixed = middle.orthonormalized();
+        assert!(fixed.is_orthonormal(1e-12));
+        assert!(near!(fixed.determinant(), 1.0, 1e-12));
+        assert_eq!(fixed[0], [1.0, 0.0, 0.0]);
+        assert_eq!(fixed[2], [0.0, 0.0, 1.0]);
+
+        //zero leading columns don't turn into NaNs
+        let last = Mat3::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 3.0, 0.0_f64);
+        let fixed = last.orthonormalized();
+        assert!(fixed.is_orthonormal(1e-12));
+        assert!(near!(fixed.determinant(), 1.0, 1e-12));
+        assert_eq!(fixed[2], [0.0, 1.0, 0.0]);
+
+        let first_zero = Mat3::new(0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 2.0_f64);
+        let fixed = first_zero.orthonormalized();
+        assert!(fixed.is_orthonormal(1e-12));
+        assert_eq!(fixed[0], [1.0, 0.0, 0.0]);
+        let zero = Mat3::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0_f64);
+        assert_eq!(zero.orthonormalized(), Mat3::default());
+    }
+
+    #[test]
+    fn vec_orthonormalize() {
+        let mut basis = [
+            Vec3::new(2.0, 0.0, 0.0_f64),
+            Vec3::new(1.0, 3.0, 0.0),
+            Vec3::new(5.0, -1.0, 0.0),
+            Vec3::new(1.0, 1.0, 1.0),
+        ];
+
+        assert_eq!(orthonormalize(&mut basis), 3);
+        assert_eq!(basis[0], Vec3::new(1.0, 0.0, 0.0));
+        assert_eq!(basis[1], Vec3::new(0.0, 1.0, 0.0));
+        assert_eq!(basis[2], Vec3::default());
+        assert_eq!(basis[3], Vec3::new(0.0, 0.0, 1.0));
+    }
+
+    fn mat3_close(a: Mat3<f64>, b: Mat3<f64>, eps: f64) -> bool {
+        (0..3).all(|i| (0..3).all(|j| near!(a[i][j], b[i][j], eps)))
+    }
+
+    #[test]
+    fn mat3_exp_log() {
+        //so(3) elements map to the rotation about their axis
+        let axis = Vec3::new(1.0, -2.0, 0.5_f64).normalized();
+        let angle = 1.3;
+        let w = axis * angle;
+        let skew = Mat3::new(0.0, w.z, -w.y, -w.z, 0.0, w.x, w.y, -w.x, 0.0);
+        let r = Mat3::rotation(angle, axis);
+        assert!(mat3_close(skew.exp(), r, 1e-12));
+        assert!(mat3_close(r.log().unwrap(), skew, 1e-12));
+
+        //rotations close to a half turn keep their axis
+        let near_pi = Mat3::rotation(std::f64::consts::PI - 1e-9, axis);
+        let log = near_pi.log().unwrap();
+        let back = Vec3::new(log[1][2], log[2][0], log[0][1]);
+        assert!(near!(back.dot(axis), std::f64::consts::PI - 1e-9, 1e-9));
+        assert!(mat3_close(log.exp(), near_pi, 1e-12));
+
+        //general matrices go through Pade
+        let diag = Mat3::new(1.0, 0.0, 0.0, 0.0, -2.0, 0.0, 0.0, 0.0, 0.5_f64);
+        let expected = Mat3::new(
+            1.0_f64.exp(),
+            0.0,
+            0.0,
+            0.0,
+            (-2.0_f64).exp(),
+            0.0,
+            0.0,
+            0.0,
+            0.5_f64.exp(),
+        );
+        assert!(mat3_close(diag.exp(), expected, 1e-12));
+        assert!(mat3_close(expected.log().unwrap(), diag, 1e-12));
+
+        let nilpotent = Mat3::new(0.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0_f64);
+        let shear = Mat3::new(1.0, 0.0, 0.0, 3.0, 1.0, 0.0, 0.0, 0.0, 1.0);
+        assert!(mat3_close(nilpotent.exp(), shear, 1e-12));
+
+        let m = Mat3::new(0.3, -1.2, 0.4, 2.0, 0.1, -0.7, 0.5, 0.9, -0.2_f64);
+        assert!(mat3_close(m.exp().log().unwrap(), m, 1e-10));
+
+        assert!(Mat3::scale(-1.0_f64).log().is_none());
+    }
+
+    #[test]
+    fn mat4_twist_exp_log() {
+        let axis = Vec3::new(0.0, 0.0, 1.0_f64);
+        let angle = std::f64::consts::FRAC_PI_2;
+
+        //rotating about z while moving along x traces a quarter circle
+        let twist = Mat4::new(
+            0.0, angle, 0.0, 0.0, -angle, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, angle, 0.0, 0.0, 0.0,
+        );
+        let pose = twist.exp();
+        let expected = Mat4::translation(Vec3::new(1.0, 1.0, 0.0)) * Mat4::rotation(angle, axis);
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!(near!(pose[i][j], expected[i][j], 1e-12));
+            }
+        }
+
+        let log = pose.log().unwrap();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!(near!(log[i][j], twist[i][j], 1e-12));
+            }
+        }
+
+        let pose = Mat4::translation(Vec3::new(0.5, -3.0, 2.0_f64))
+            * Mat4::rotation(2.5, Vec3::new(1.0, 1.0, -1.0));
+        let back = pose.log().unwrap().exp();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!(near!(back[i][j], pose[i][j], 1e-12));
+            }
+        }
+
+        assert!(Mat4::scale(2.0_f64).log().is_none());
+    }
+
+    #[test]
+    fn quaternion_product_and_matrix() {
+        let a = Quaternion::new(0.8, Vec3::new(1.0, 2.0, 3.0_f64));
+        let b = Quaternion::new(-1.9, Vec3::new(-0.5, 0.1, 1.0));
+        let v = Vec3::new(0.3, -2.0, 1.5);
+
+        //composing quaternions matches multiplying their matrices
+        let ab = Mat3::from(a * b);
+        let expected = Mat3::from(a) * Mat3::from(b);
+        assert!(mat3_close(ab, expected, 1e-12));
+
+        let rotated = (a * b).rotate(v);
+        let by_matrix = expected * v;
+        assert!(near!((rotated - by_matrix).magnitude(), 0.0, 1e-12));
+
+        //every branch of the matrix conversion gives back the same rotation
+        for &(angle, axis) in [
+            (0.5, Vec3::new(0.2, 0.3, 0.9)),
+            (3.0, Vec3::new(1.0, 0.1, 0.1)),
+            (3.0, Vec3::new(0.1, 1.0, 0.1)),
+            (3.0, Vec3::new(0.1, 0.1, 1.0_f64)),
+        ]
+        .iter()
+        {
+            let m = Mat3::rotation(angle, axis);
+            assert!(mat3_close(Mat3::from(Quaternion::from(m)), m, 1e-12));
+        }
+    }
+
+    fn twist_close(a: (Vec3<f64>, Vec3<f64>), b: (Vec3<f64>, Vec3<f64>), eps: f64) -> bool {
+        (a.0 - b.0).magnitude() < eps && (a.1 - b.1).magnitude() < eps
+    }
+
+    //applies a column major 6x6 matrix to the twist (rho, phi)
+    fn mul6(m: &[[f64; 6]; 6], rho: Vec3<f64>, phi: Vec3<f64>) -> (Vec3<f64>, Vec3<f64>) {
+        let x = [rho.x, rho.y, rho.z, phi.x, phi.y, phi.z];
+        let mut y = [0.0; 6];
+        for (j, column) in m.iter().enumerate() {
+            for (i, yi) in y.iter_mut().enumerate() {
+                *yi += column[i] * x[j];
+            }
+        }
+        (Vec3::new(y[0], y[1], y[2]), Vec3::new(y[3], y[4], y[5]))
+    }
+
+    #[test]
+    fn so3_exp_log_jacobians() {
+        let w = Vec3::new(0.4, -1.1, 0.7_f64);
+        let r = SO3::exp(w);
+
+        assert!(mat3_close(r.matrix(), Mat3::exp(&so3::hat(w)), 1e-12));
+        assert!((r.log() - w).magnitude() < 1e-12);
+        assert!((so3::log(so3::exp(w)) - w).magnitude() < 1e-12);
+        assert!((so3::vee(so3::hat(w)) - w).magnitude() < 1e-12);
+        let mut lower = so3::hat(w);
+        for col in 1..3 {
+            for row in 0..col {
+                lower[col][row] = f64::NAN;
+            }
+        }
+        assert_eq!(so3::vee(lower), w);
+        assert!((SO3::exp(Vec3::new(1e-10, 0.0, 0.0_f64)).log().x - 1e-10).abs() < 1e-20);
+
+        let d = Vec3::new(0.05, 0.02, -0.03);
+        assert!((r.boxplus(d).boxminus(&r) - d).magnitude() < 1e-12);
+
+        //central differences of the exponential map
+        let h = 1e-6;
+        let (jl, jr) = (so3::left_jacobian(w), so3::right_jacobian(w));
+        for k in 0..3 {
+            let mut e = Vec3::default();
+            e[k] = h;
+            let plus = SO3::exp(w + e);
+            let minus = SO3::exp(w - e);
+
+            let left = ((plus * r.inverse()).log() - (minus * r.inverse()).log()) / (2.0 * h);
+            let right = ((r.inverse() * plus).log() - (r.inverse() * minus).log()) / (2.0 * h);
+            assert!((left - Vec3::from(jl[k])).magnitude() < 1e-8);
+            assert!((right - Vec3::from(jr[k])).magnitude() < 1e-8);
+        }
+
+        let inv = so3::left_jacobian_inverse(w) * jl;
+        assert!(mat3_close(inv, Mat3::default(), 1e-12));
+        let inv = so3::right_jacobian_inverse(w) * jr;
+        assert!(mat3_close(inv, Mat3::default(), 1e-12));
+    }
+
+    #[test]
+    fn se3_exp_log_adjoint_jacobians() {
+        let q = Quaternion::new(1.2, Vec3::new(0.0, 1.0, 1.0_f64));
+        let t = SE3::new(q, Vec3::new(1.0, -2.0, 0.5));
+        let p = Vec3::new(0.3, 0.2, -4.0);
+
+        let m = Mat4::from(t) * Vec4::new(p.x, p.y, p.z, 1.0);
+        assert!((Vec3::new(m.x, m.y, m.z) - t.transform_point(p)).magnitude() < 1e-12);
+        assert!((t.inverse().transform_point(t.transform_point(p)) - p).magnitude() < 1e-12);
+
+        let (rho, phi) = t.log();
+        let back = SE3::exp(rho, phi);
+        assert!((back.translation - t.translation).magnitude() < 1e-12);
+        assert!(mat3_close(
+            back.rotation.matrix(),
+            t.rotation.matrix(),
+            1e-12
+        ));
+
+        //agrees with the matrix exponential of the twist
+        let twist = se3::hat(rho, phi);
+        assert!(twist_close(se3::vee(twist), (rho, phi), 1e-15));
+        let by_matrix = twist.exp();
+        let direct = t.matrix();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!(near!(by_matrix[i][j], direct[i][j], 1e-12));
+            }
+        }
+
+        let d = (Vec3::new(0.1, 0.0, -0.2), Vec3::new(0.01, 0.03, 0.02));
+        assert!(twist_close(t.boxplus(d.0, d.1).boxminus(&t), d, 1e-12));
+
+        //t * exp(d) * t^-1 == exp(Ad * d)
+        let (a_rho, a_phi) = mul6(&t.adjoint(), d.0, d.1);
+        let lhs = t * SE3::exp(d.0, d.1) * t.inverse();
+        assert!(twist_close(lhs.log(), (a_rho, a_phi), 1e-12));
+
+        //the second twist is small enough to go through the series expansions
+        for &xi in [
+            (Vec3::new(0.5, -1.0, 2.0), Vec3::new(-0.6, 0.9, 1.4)),
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(0.004, -0.003, 0.002)),
+        ]
+        .iter()
+        {
+            let x = SE3::exp(xi.0, xi.1);
+            let (jl, jr) = (
+                se3::left_jacobian(xi.0, xi.1),
+                se3::right_jacobian(xi.0, xi.1),
+            );
+            let h = 1e-6;
+            for k in 0..6 {
+                let mut e = [Vec3::default(); 2];
+                e[k / 3][k % 3] = h;
+                let plus = SE3::exp(xi.0 + e[0], xi.1 + e[1]);
+                let minus = SE3::exp(xi.0 - e[0], xi.1 - e[1]);
+
+                let (lp, lm) = ((plus * x.inverse()).log(), (minus * x.inverse()).log());
+                let (rp, rm) = ((x.inverse() * plus).log(), (x.inverse() * minus).log());
+                let left = ((lp.0 - lm.0) / (2.0 * h), (lp.1 - lm.1) / (2.0 * h));
+                let right = ((rp.0 - rm.0) / (2.0 * h), (rp.1 - rm.1) / (2.0 * h));
+
+                let column = |m: &[[f64; 6]; 6]| {
+                    (
+                        Vec3::new(m[k][0], m[k][1], m[k][2]),
+                        Vec3::new(m[k][3], m[k][4], m[k][5]),
+                    )
+                };
+                assert!(twist_close(left, column(&jl), 1e-8));
+                assert!(twist_close(right, column(&jr), 1e-8));
+            }
+        }
+    }
+
+    #[test]
+    fn dual_derivatives() {
+        let x = 0.7_f64;
+        let f =
+            |x: Dual<f64>| x.sin() * x.exp() + x.powi(3) / x.sqrt() - x.atan2(Dual::constant(2.0));
+
+        let d = f(Dual::variable(x, 0));
+        let expected =
+            x.cos() * x.exp() + x.sin() * x.exp() + 2.5 * x.powf(1.5) - 2.0 / (4.0 + x * x);
+        assert!(near!(d.re, f(Dual::constant(x)).re, 1e-15));
+        assert!(near!(d.derivative(), expected, 1e-12));
+
+        //the derivative of a rotation by its angle is the cross product with the axis
+        let angle = Dual::variable(0.4, 0);
+        let axis = Vec3::new(0.0, 0.0, 1.0).map(Dual::constant);
+        let p = Vec4::new(1.0, 2.0, 3.0, 1.0_f64).map(Dual::constant);
+        let rotated = Mat4::rotation(angle, axis) * p;
+        let expected =
+            Vec3::new(0.0, 0.0, 1.0).cross(Vec3::new(rotated.x.re, rotated.y.re, rotated.z.re));
+        assert!(near!(rotated.x.derivative(), expected.x, 1e-15));
+        assert!(near!(rotated.y.derivative(), expected.y, 1e-15));
+        assert!(near!(rotated.z.derivative(), 0.0, 1e-15));
+
+        //the value of a quotient is exactly the one of the plain division
+        let q = Dual::variable(0.3_f64, 0) / Dual::constant(0.1);
+        assert_eq!(q.re, 0.3 / 0.1);
+        assert!(near!(q.derivative(), 10.0, 1e-12));
+
+        //sqrt of a constant zero has no derivative instead of 0 * inf
+        let root = Dual::constant(0.0_f64).sqrt();
+        assert_eq!((root.re, root.derivative()), (0.0, 0.0));
+        assert!(Dual::variable(0.0_f64, 0).sqrt().derivative().is_infinite());
+    }
+
+    #[test]
+    fn dual_jacobians() {
+        //rotating by a Quaternion is linear, its Jacobian is the rotation matrix
+        let q = Quaternion::new(1.1, Vec3::new(1.0, -1.0, 2.0_f64));
+        let j = jacobian(
+            |v: [DualN<f64, 3>; 3]| {
+                let q = Quaternion::new_sv(DualN::constant(q.w), q.v.map(DualN::constant));
+                let r = q.rotate(Vec3::new(v[0], v[1], v[2]));
+                [r.x, r.y, r.z]
+            },
+            [0.3, -0.2, 0.9],
+        );
+        assert!(mat3_close(Mat3::from(j), Mat3::from(q), 1e-12));
+
+        //d(v / |v|) / dv = (I - n * n^T) / |v|
+        let v = Vec3::new(1.0, 2.0, -2.0_f64);
+        let j = jacobian(
+            |v: [DualN<f64, 3>; 3]| {
+                let n = Vec3::new(v[0], v[1], v[2]).normalized();
+                [n.x, n.y, n.z]
+            },
+            [v.x, v.y, v.z],
+        );
+        let n = v.normalized();
+        for i in 0..3 {
+            for k in 0..3 {
+                let identity = if i == k { 1.0 } else { 0.0 };
+                assert!(near!(j[k][i], (identity - n[i] * n[k]) / 3.0, 1e-12));
+            }
+        }
+
+        //d(A^-1) = -A^-1 * dA * A^-1, differentiating along the first element of A
+        let a = Mat3::new(2.0, 1.0, 0.0, -1.0, 3.0, 1.0, 0.5, 0.0, 1.0_f64);
+        let j = jacobian(
+            |x: [DualN<f64, 1>; 1]| {
+                let mut m = Mat3::from(
+                    [
+                        [a[0][0], a[0][1], a[0][2]],
+                        [a[1][0], a[1][1], a[1][2]],
+                        [a[2][0], a[2][1], a[2][2]],
+                    ]
+                    .map(|c| c.map(DualN::constant)),
+                );
+                m[0][0] = x[0];
+                let inv = m.inverse().unwrap();
+                [
+                    inv[0][0], inv[1][0], inv[2][0], inv[0][1], inv[1][1], inv[2][1], inv[0][2],
+                    inv[1][2], inv[2][2],
+                ]
+            },
+            [a[0][0]],
+        );
+        let inv = a.inverse().unwrap();
+        let mut da = Mat3::zero();
+        da[0][0] = 1.0;
+        let expected = inv * da * inv * -1.0;
+        for i in 0..3 {
+            for k in 0..3 {
+                assert!(near!(j[0][3 * i + k], expected[k][i], 1e-12));
+            }
+        }
+    }
+
+    #[test]
+    fn complex_arithmetic() {
+        let a = Complex::new(1.0, 2.0_f64);
+        let b = Complex::new(-3.0, 0.5);
+
+        assert_eq!(a * b, Complex::new(-4.0, -5.5));
+        assert_eq!(a * b / b, a);
+        assert_eq!(Complex::<f64>::i() * Complex::i(), Complex::new(-1.0, 0.0));
+        assert_eq!(a.conj(), Complex::new(1.0, -2.0));
+        assert_eq!(Complex::new(3.0, 4.0_f64).norm(), 5.0);
+
+        let e = Complex::new(0.0, std::f64::consts::PI).exp();
+        assert!(near!(e.re, -1.0, 1e-15));
+        assert!(near!(e.im, 0.0, 1e-15));
+        let r = Complex::new(-4.0, 0.0_f64).sqrt();
+        assert!(near!(r.re, 0.0, 1e-15));
+        assert!(near!(r.im, 2.0, 1e-15));
+        let l = a.ln().exp();
+        assert!(near!((l - a).norm(), 0.0, 1e-15));
+
+        //gaussian integers
+        assert_eq!(Complex::new(7, 3) % Complex::new(2, 0), Complex::new(1, 1));
+    }
+
+    #[test]
+    fn complex_matrices() {
+        let i = Complex::<f64>::i();
+        let one = Complex::new(1.0, 0.0);
+        let zero = Complex::new(0.0, 0.0);
+
+        //the Pauli matrices square to the identity and anticommute
+        let x = Mat2::new(zero, one, one, zero);
+        let y = Mat2::new(zero, i, -i, zero);
+        let z = Mat2::new(one, zero, zero, -one);
+        assert_eq!(x * x, Mat2::default());
+        assert_eq!(y * y, Mat2::default());
+        assert_eq!(x * y + y * x, Mat2::zero());
+        assert_eq!(x * y, z * i);
+        assert_eq!(y.determinant(), -one);
+        assert_eq!(y.transpost(), Mat2::new(zero, -i, i, zero));
+
+        let v = Vec2::new(one, i);
+        assert_eq!(y * v, v);
+        assert_eq!(y * Vec2::new(one, one), Vec2::new(-i, i));
+        assert_eq!(
+            v * i + v,
+            Vec2::new(Complex::new(1.0, 1.0), Complex::new(-1.0, 1.0))
+        );
+
+        let m = Mat3::default() * Complex::new(0.0, 2.0_f64);
+        assert_eq!((m * m)[1][1], Complex::new(-4.0, 0.0));
+
+        //the Pauli matrices are their own inverses
+        assert_eq!(y.inverse(), Some(y));
+        let a = Mat2::new(
+            Complex::new(1.0, 1.0),
+            i,
+            Complex::new(2.0, 0.0),
+            Complex::new(1.0, -1.0),
+        );
+        assert_eq!(a.determinant(), Complex::new(2.0, -2.0));
+        let inv = a.inverse().unwrap();
+        assert_eq!(a * inv, Mat2::default());
+        assert_eq!(inv * a, Mat2::default());
+        assert_eq!(Mat2::new(one, i, i, -one).inverse(), None);
+    }
+
+    #[test]
+    fn rotor2_rotations() {
+        let a = Rotor2::new(0.5_f64);
+        let b = Rotor2::new(1.2);
+        let v = Vec2::new(2.0, -1.0);
+
+        let m = Mat2::from(a);
+        //Rotor2 and Mat3::rotation_2d turn counterclockwise, Mat2::rotation clockwise
+        for k in -6..=6 {
+            let angle = f64::from(k) * 0.55;
+            let rotor = Rotor2::new(angle).to_mat2();
+            let r = Mat2::rotation(-angle);
+            for i in 0..2 {
+                for j in 0..2 {
+                    assert!(near!(rotor[i][j], r[i][j], 1e-15));
+                }
+            }
+            assert!(mat3_close(
+                Rotor2::new(angle).to_mat3(),
+                Mat3::rotation_2d(angle),
+                1e-15
+            ));
+        }
+        assert!(mat3_close(Mat3::from(a), Mat3::rotation_2d(0.5), 1e-15));
+        let quarter = Mat2::rotation(90.0_f64.to_radians()) * Vec2::new(1.0, 0.0);
+        assert!((quarter - Vec2::new(0.0, -1.0)).magnitude() < 1e-15);
+        assert!(near!(
+            Rotor2::new(0.25_f64).rotate(Vec2::new(1.0, 0.0)).y,
+            0.25_f64.sin(),
+            1e-15
+        ));
+
+        assert!(near!((a * b).angle(), 1.7, 1e-15));
+        let back = a.inverse() * (a * v);
+        assert!(near!((back - v).magnitude(), 0.0, 1e-15));
+        assert!(((a * b) * v - m * (Mat2::from(b) * v)).magnitude() < 1e-14);
+
+        //the shortest way from 170 to -170 degrees crosses 180
+        let from = Rotor2::new(170.0_f64.to_radians());
+        let to = Rotor2::new(-170.0_f64.to_radians());
+        assert!(near!(
+            from.slerp(to, 0.5).angle().abs(),
+            std::f64::consts::PI,
+            1e-12
+        ));
+        assert!(near!(a.slerp(b, 0.25).angle(), 0.675, 1e-15));
+
+        let between = Rotor2::between(Vec2::new(1.0, 1.0), Vec2::new(-3.0, 3.0));
+        assert!(near!(between.angle(), std::f64::consts::FRAC_PI_2, 1e-15));
+    }
+
+    #[test]
+    fn rotor3_rotations() {
+        let a = Vec3::new(1.0, 0.0, 0.0_f64);
+        let b = Vec3::new(0.0, 1.0, 0.0);
+        let plane = a.wedge(b);
+        assert_eq!(plane, Bivector3::new(1.0, 0.0, 0.0));
+        assert_eq!(plane.normal(), a.cross(b));
+
+        //rotating in the xy plane turns x towards y, like a rotation about z
+        let r = Rotor3::from_angle_plane(0.7, plane);
+        assert!(mat3_close(r.to_mat3(), Mat3::rotation_z(0.7), 1e-15));
+        let v = Vec3::new(0.3, -1.0, 2.0);
+        assert!((r.rotate(v) - Mat3::rotation_z(0.7) * v).magnitude() < 1e-15);
+
+        //exact round trips through Quaternion, and matching behaviour
+        let q = Quaternion::new(2.1, Vec3::new(1.0, -2.0, 0.5_f64));
+        let p = Quaternion::new(-0.4, Vec3::new(0.0, 1.0, 1.0));
+        let (rq, rp) = (Rotor3::from(q), Rotor3::from(p));
+        assert_eq!(Quaternion::from(rq).w, q.w);
+        assert_eq!(Quaternion::from(rq).v, q.v);
+        assert!(mat3_close(Mat3::from(rq), Mat3::from(q), 1e-15));
+        assert!(mat3_close((rq * rp).to_mat3(), Mat3::from(q * p), 1e-14));
+        assert!(mat3_close(
+            Mat3::from(Rotor3::from(Mat3::from(q))),
+            Mat3::from(q),
+            1e-14
+        ));
+        assert!(((rq * rq.reverse()).s - 1.0).abs() < 1e-15);
+
+        let between =
+            Rotor3::from_rotation_between(Vec3::new(1.0, 2.0, 3.0), Vec3::new(-2.0, 0.5, 1.0));
+        let moved = between.rotate(Vec3::new(1.0, 2.0, 3.0).normalized());
+        assert!((moved - Vec3::new(-2.0, 0.5, 1.0).normalized()).magnitude() < 1e-14);
+        let flip = Rotor3::from_rotation_between(a, -a);
+        assert!((flip.rotate(a) + a).magnitude() < 1e-15);
+
+        let half = Rotor3::identity().slerp(r, 0.5);
+        assert!(mat3_close(half.to_mat3(), Mat3::rotation_z(0.35), 1e-15));
+        assert!(mat3_close(
+            rq.slerp(rp, 0.3).to_mat3(),
+            Mat3::from(q.slerp(p, 0.3)),
+            1e-12
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_json_round_trip() {
+        let v = Vec3::new(1.0, -2.5, 3.0_f64);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "[1.0,-2.5,3.0]");
+        assert_eq!(serde_json::from_str::<Vec3<f64>>(&json).unwrap(), v);
+        assert_eq!(
+            serde_json::from_str::<Vec3<f64>>(r#"{"z": 3.0, "x": 1.0, "y": -2.5}"#).unwrap(),
+            v
+        );
+        assert!(serde_json::from_str::<Vec3<f64>>("[1.0,2.0]").is_err());
+        assert!(serde_json::from_str::<Vec3<f64>>("[1.0,2.0,3.0,4.0]").is_err());
+        assert!(serde_json::from_str::<Vec3<f64>>(r#"{"x": 1.0, "y": 2.0}"#).is_err());
+        assert!(serde_json::from_str::<Vec3<f64>>(r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0}"#).is_err());
+
+        //column major
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0_f32);
+        assert_eq!(serde_json::to_string(&m).unwrap(), "[1.0,2.0,3.0,4.0]");
+        let m = Mat4::translation(Vec3::new(5.0, 6.0, 7.0_f64));
+        let json = serde_json::to_string(&m).unwrap();
+        assert!(json.ends_with("5.0,6.0,7.0,1.0]"));
+        assert_eq!(serde_json::from_str::<Mat4<f64>>(&json).unwrap(), m);
+
+        let q = Quaternion::new(0.7, Vec3::new(1.0, 2.0, -1.0_f64));
+        let json = serde_json::to_string(&q).unwrap();
+        assert!(json.starts_with(&format!("[{},", q.w)));
+        let back = serde_json::from_str::<Quaternion<f64>>(&json).unwrap();
+        assert_eq!((back.w, back.v), (q.w, q.v));
+
+        let e = Euler::new(0.1, -0.2, 0.3_f32);
+        let json = serde_json::to_string(&e).unwrap();
+        assert_eq!(json, "[0.1,-0.2,0.3]");
+        assert_eq!(serde_json::from_str::<Euler<f32>>(&json).unwrap(), e);
+        let mut named_json = Vec::new();
+        named::serialize(&e, &mut serde_json::Serializer::new(&mut named_json)).unwrap();
+        assert_eq!(
+            String::from_utf8(named_json).unwrap(),
+            r#"{"yaw":0.1,"pitch":-0.2,"roll":0.3}"#
+        );
+        //the roll used to be written as `row`
+        assert_eq!(
+            serde_json::from_str::<Euler<f32>>(r#"{"yaw":0.1,"pitch":-0.2,"row":0.3}"#).unwrap(),
+            e
+        );
+        assert!(serde_json::from_str::<Euler<f32>>(
+            r#"{"yaw":0.1,"pitch":-0.2,"roll":0.3,"row":0.3}"#
+        )
+        .is_err());
+
+        let mut named_json = Vec::new();
+        named::serialize(&q, &mut serde_json::Serializer::new(&mut named_json)).unwrap();
+        let named_json = String::from_utf8(named_json).unwrap();
+        assert!(named_json.starts_with(r#"{"w":"#));
+        let back: Quaternion<f64> = serde_json::from_str(&named_json).unwrap();
+        assert_eq!((back.w, back.v), (q.w, q.v));
+
+        let m = Mat2::new(1.0, 2.0, 3.0, 4.0_f64);
+        let mut named_json = Vec::new();
+        named::serialize(&m, &mut serde_json::Serializer::new(&mut named_json)).unwrap();
+        assert_eq!(
+            String::from_utf8(named_json).unwrap(),
+            r#"{"col0":[1.0,2.0],"col1":[3.0,4.0]}"#
+        );
+        let back: Mat2<f64> = named::deserialize(&mut serde_json::Deserializer::from_str(
+            r#"{"col1":[3.0,4.0],"col0":[1.0,2.0]}"#,
+        ))
+        .unwrap();
+        assert_eq!(back, m);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_binary_round_trip() {
+        let m = Mat4::translation(Vec3::new(5.0, 6.0, 7.0_f32)) * Mat4::rotation_x(0.3);
+        let bytes = bincode::serialize(&m).unwrap();
+        assert_eq!(bytes.len(), 16 * 4);
+        //no length prefix, the scalars in column major order
+        assert_eq!(&bytes[48..52], &5.0_f32.to_le_bytes());
+        assert_eq!(bincode::deserialize::<Mat4<f32>>(&bytes).unwrap(), m);
+        assert!(bincode::deserialize::<Mat4<f32>>(&bytes[..60]).is_err());
+
+        let mut named_bytes = Vec::new();
+        named::serialize(
+            &m,
+            &mut bincode::Serializer::new(&mut named_bytes, bincode::options()),
+        )
+        .unwrap();
+        let bytes = bincode::serialize(&m).unwrap();
+        assert_eq!(named_bytes, bytes);
+        let back: Mat4<f32> = named::deserialize(&mut bincode::Deserializer::from_slice(
+            &bytes,
+            bincode::options(),
+        ))
+        .unwrap();
+        assert_eq!(back, m);
+
+        let q = Quaternion::new(-1.2, Vec3::new(0.0, 1.0, 1.0_f64));
+        let bytes = bincode::serialize(&q).unwrap();
+        assert_eq!(bytes.len(), 4 * 8);
+        let back = bincode::deserialize::<Quaternion<f64>>(&bytes).unwrap();
+        assert_eq!((back.w, back.v), (q.w, q.v));
+
+        type Values = (
+            Vec2<i32>,
+            Vec4<f32>,
+            Mat3<f64>,
+            Euler<f64>,
+            Complex<f32>,
+            Rotor3<f64>,
+        );
+        let values: Values = (
+            Vec2::new(1, -2),
+            Vec4::new(1.0, 2.0, 3.0, 4.0),
+            Mat3::rotation_z(0.5),
+            Euler::new(0.1, 0.2, 0.3),
+            Complex::new(1.0, -1.0),
+            Rotor3::from(q),
+        );
+        let bytes = bincode::serialize(&values).unwrap();
+        let back: Values = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(back, values);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_pod_casts() {
+        let points = [Vec3::new(1.0, 2.0, 3.0_f32), Vec3::new(4.0, 5.0, 6.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&points);
+        assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_eq!(bytemuck::cast_slice::<_, u8>(&points).len(), 24);
+
+        let m = Mat4::translation(Vec3::new(7.0, 8.0, 9.0_f32));
+        let columns: &[[f32; 4]; 4] = bytemuck::cast_ref(&m);
+        assert_eq!(columns[3], [7.0, 8.0, 9.0, 1.0]);
+        assert_eq!(bytemuck::cast::<[[f32; 4]; 4], Mat4<f32>>(*columns), m);
+
+        let q: Quaternion<f32> = bytemuck::cast([1.0_f32, 2.0, 3.0, 4.0]);
+        assert_eq!((q.w, q.v), (1.0, Vec3::new(2.0, 3.0, 4.0)));
+        assert_eq!(
+            bytemuck::cast::<_, [f64; 3]>(Euler::new(1.0, 2.0, 3.0_f64)),
+            [1.0, 2.0, 3.0]
+        );
+        assert_eq!(bytemuck::cast::<_, [i32; 2]>(Vec2::new(-1, 1)), [-1, 1]);
+        assert_eq!(
+            bytemuck::cast::<[u32; 3], Vec3<u32>>([0; 3]),
+            bytemuck::Zeroable::zeroed()
+        );
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn std140_std430_layouts() {
+        fn floats(bytes: &[u8]) -> Vec<f32> {
+            bytes
+                .chunks(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect()
+        }
+
+        assert_eq!(
+            Vec3::new(1.0, 2.0, 3.0_f32).as_std140(),
+            [1.0, 2.0, 3.0, 0.0]
+        );
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0_f32);
+        assert_eq!(
+            floats(bytemuck::bytes_of(&m.as_std140())),
+            [1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0, 7.0, 8.0, 9.0, 0.0]
+        );
+        let m2 = Mat2::new(1.0, 2.0, 3.0, 4.0_f32);
+        assert_eq!(
+            floats(bytemuck::bytes_of(&m2.as_std140())),
+            [1.0, 2.0, 0.0, 0.0, 3.0, 4.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            floats(bytemuck::bytes_of(&m2.as_std430())),
+            [1.0, 2.0, 3.0, 4.0]
+        );
+
+        //uniform Block { float a; vec3 b; float c; mat3 d; vec2 e; float f[2]; }
+        let mut block = Std140Buffer::new();
+        assert_eq!(block.push(&1.0_f32), 0);
+        assert_eq!(block.push(&Vec3::new(2.0, 3.0, 4.0_f32)), 16);
+        //a scalar fills the padding of a vec3
+        assert_eq!(block.push(&5.0_f32), 28);
+        assert_eq!(block.push(&m), 32);
+        assert_eq!(block.push(&Vec2::new(10.0, 11.0_f32)), 80);
+        assert_eq!(block.push_array(&[12.0_f32, 13.0]), 96);
+        let bytes = block.into_bytes();
+        assert_eq!(bytes.len(), 128);
+        assert_eq!(
+            floats(&bytes),
+            [
+                1.0, 0.0, 0.0, 0.0, 2.0, 3.0, 4.0, 5.0, 1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0,
+                7.0, 8.0, 9.0, 0.0, 10.0, 11.0, 0.0, 0.0, 12.0, 0.0, 0.0, 0.0, 13.0, 0.0, 0.0, 0.0
+            ]
+        );
+
+        //buffer Block { float a; vec3 b[2]; mat2 c; vec2 d; float e[2]; }
+        let mut block = Std430Buffer::new();
+        assert_eq!(block.push(&1.0_f32), 0);
+        assert_eq!(
+            block.push_array(&[Vec3::new(2.0, 3.0, 4.0_f32), Vec3::new(5.0, 6.0, 7.0)]),
+            16
+        );
+        assert_eq!(block.push(&m2), 48);
+        assert_eq!(block.push(&Vec2::new(8.0, 9.0_f32)), 64);
+        assert_eq!(block.push_array(&[10.0_f32, 11.0]), 72);
+        let bytes = block.into_bytes();
+        assert_eq!(bytes.len(), 80);
+        assert_eq!(
+            floats(&bytes),
+            [
+                1.0, 0.0, 0.0, 0.0, 2.0, 3.0, 4.0, 0.0, 5.0, 6.0, 7.0, 0.0, 1.0, 2.0, 3.0, 4.0,
+                8.0, 9.0, 10.0, 11.0
+            ]
+        );
+
+        let mut ints = Std430Buffer::new();
+        ints.push(&Vec3::new(1, -2, 3));
+        ints.push(&4_u32);
+        assert_eq!(ints.into_bytes().len(), 16);
+    }
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_conversions() {
+        let v: mint::Vector3<f32> = Vec3::new(1.0, 2.0, 3.0).into();
+        assert_eq!((v.x, v.y, v.z), (1.0, 2.0, 3.0));
+        assert_eq!(Vec3::from(v), Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vec2::from(mint::Point2 { x: 4, y: 5 }), Vec2::new(4, 5));
+
+        let m = Mat4::translation(Vec3::new(7.0, 8.0, 9.0_f64));
+        let columns: mint::ColumnMatrix4<f64> = m.into();
+        assert_eq!((columns.w.x, columns.w.y, columns.w.z), (7.0, 8.0, 9.0));
+        let rows: mint::RowMatrix4<f64> = m.into();
+        assert_eq!((rows.x.w, rows.y.w, rows.z.w), (7.0, 8.0, 9.0));
+        assert_eq!(Mat4::from(columns), m);
+        assert_eq!(Mat4::from(rows), m);
+
+        let q = Quaternion::new(0.5, Vec3::new(0.0, 0.0, 1.0_f32));
+        let mq: mint::Quaternion<f32> = q.into();
+        assert_eq!((mq.s, mq.v.z), (q.w, q.v.z));
+        let back = Quaternion::from(mq);
+        assert_eq!((back.w, back.v), (q.w, q.v));
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_conversions() {
+        let m = Mat4::translation(Vec3::new(1.0, 2.0, 3.0_f32)) * Mat4::rotation_z(0.4);
+        let g = glam::Mat4::from(m);
+        let p = Vec3::new(0.5, -1.0, 2.0_f32);
+        let moved = m * Vec4::new(p.x, p.y, p.z, 1.0);
+        let expected = g.transform_point3(p.into());
+        assert!((Vec3::from(expected) - Vec3::new(moved.x, moved.y, moved.z)).magnitude() < 1e-6);
+        assert_eq!(Mat4::from(g), m);
+        assert_eq!(
+            Mat3::from(glam::DMat3::from(Mat3::rotation_x(0.3_f64))),
+            Mat3::rotation_x(0.3)
+        );
+
+        let q = Quaternion::new(1.1, Vec3::new(1.0, 2.0, -0.5_f64));
+        let gq = glam::DQuat::from(q);
+        assert_eq!((gq.w, gq.x, gq.y, gq.z), (q.w, q.v.x, q.v.y, q.v.z));
+        let rotated = Vec3::from(gq * glam::DVec3::new(0.3, 0.1, 2.0));
+        assert!((rotated - q.rotate(Vec3::new(0.3, 0.1, 2.0))).magnitude() < 1e-14);
+        let back = Quaternion::from(gq);
+        assert_eq!((back.w, back.v), (q.w, q.v));
+        assert_eq!(Vec2::from(glam::IVec2::new(-1, 2)), Vec2::new(-1, 2));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra_conversions() {
+        let m = Mat3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0_f64);
+        let n = nalgebra::Matrix3::from(m);
+        //the first column
+        assert_eq!((n[(0, 0)], n[(1, 0)], n[(2, 0)]), (1.0, 2.0, 3.0));
+        assert_eq!(Mat3::from(n), m);
+        let v = Vec3::new(1.0, -1.0, 0.5);
+        assert_eq!(Vec3::from(n * nalgebra::Vector3::from(v)), m * v);
+        assert_eq!(
+            Vec3::from(nalgebra::Point3::new(1.0, 2.0, 3.0)),
+            Vec3::new(1.0, 2.0, 3.0)
+        );
+
+        let q = Quaternion::new(-0.8, Vec3::new(0.2, 1.0, 0.4_f64));
+        let nq = nalgebra::Quaternion::from(q);
+        assert_eq!((nq.w, nq.i, nq.j, nq.k), (q.w, q.v.x, q.v.y, q.v.z));
+        let unit = nalgebra::UnitQuaternion::from(q);
+        let rotated = Vec3::from(unit * nalgebra::Vector3::new(0.3, 0.1, 2.0));
+        assert!((rotated - q.rotate(Vec3::new(0.3, 0.1, 2.0))).magnitude() < 1e-14);
+        let back = Quaternion::from(unit);
+        assert!((back.w - q.w).abs() < 1e-15 && (back.v - q.v).magnitude() < 1e-15);
+    }
+
+    #[cfg(feature = "cgmath")]
+    #[test]
+    fn cgmath_conversions() {
+        use cgmath::Rotation;
+
+        let m = Mat4::translation(Vec3::new(1.0, 2.0, 3.0_f32));
+        let c = cgmath::Matrix4::from(m);
+        assert_eq!((c.w.x, c.w.y, c.w.z, c.w.w), (1.0, 2.0, 3.0, 1.0));
+        assert_eq!(Mat4::from(c), m);
+        assert_eq!(
+            Mat2::from(cgmath::Matrix2::new(1, 2, 3, 4)),
+            Mat2::new(1, 2, 3, 4)
+        );
+
+        let q = Quaternion::new(0.9, Vec3::new(0.0, 1.0, 1.0_f64));
+        let cq = cgmath::Quaternion::from(q);
+        assert_eq!((cq.s, cq.v.x, cq.v.y, cq.v.z), (q.w, q.v.x, q.v.y, q.v.z));
+        let rotated = Vec3::from(cq.rotate_vector(cgmath::Vector3::new(0.3, 0.1, 2.0)));
+        assert!((rotated - q.rotate(Vec3::new(0.3, 0.1, 2.0))).magnitude() < 1e-14);
+        assert_eq!(Vec3::from(cgmath::Point3::new(1, 2, 3)), Vec3::new(1, 2, 3));
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_matches_generic() {
+        use crate::simd::{kernels, Fallback};
+
+        //`DualN<f32, 0>` does the f32 arithmetic of the generic code without reaching the kernels
+        type Generic = DualN<f32, 0>;
+        let g = Generic::constant;
+        let bits = |values: &[f32]| values.iter().map(|x| x.to_bits()).collect::<Vec<_>>();
+        let mat = |m: Mat4<f32>| bits(&m.mat.concat());
+        let generic_mat = |m: Mat4<Generic>| bits(&m.map(|x| x.re).mat.concat());
+        let vec3 = |v: Vec3<f32>| bits(&[v.x, v.y, v.z]);
+        let generic_vec3 = |v: Vec3<Generic>| bits(&[v.x.re, v.y.re, v.z.re]);
+        let vec4 = |v: Vec4<f32>| bits(&[v.x, v.y, v.z, v.w]);
+        let quat = |q: Quaternion<f32>| bits(&[q.w, q.v.x, q.v.y, q.v.z]);
+
+        let mut seed = 0x2545_f491_u32;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            (seed as f32 / u32::MAX as f32) * 8.0 - 4.0
+        };
+
+        for round in 0..200 {
+            let mut a = Mat4::zero();
+            let mut b = Mat4::zero();
+            for i in 0..4 {
+                for j in 0..4 {
+                    a[i][j] = next();
+                    b[i][j] = next();
+                }
+            }
+            let v = Vec4::new(next(), next(), next(), next());
+            let w = Vec4::new(next(), next(), next(), next());
+            //equal products make some cross terms zero, whose sign has to match as well
+            if round % 10 == 0 {
+                a[3] = a[2];
+                b[1][2] = b[1][1];
+            }
+            let (p, q) = (v.truncate(), w.truncate());
+            let p = if round % 10 == 0 {
+                p.map(|x| x.round())
+            } else {
+                p
+            };
+            let q = if round % 10 == 0 { p * 2.0 } else { q };
+            let (qa, qb) = (Quaternion::new_sv(v.w, p), Quaternion::new_sv(w.w, q));
+
+            let (ga, gb) = (a.map(g), b.map(g));
+            let gv = Vec4::new(g(v.x), g(v.y), g(v.z), g(v.w));
+            let gw = Vec4::new(g(w.x), g(w.y), g(w.z), g(w.w));
+            let (gp, gq) = (p.map(g), q.map(g));
+            let (gqa, gqb) = (
+                Quaternion::new_sv(g(qa.w), gp),
+                Quaternion::new_sv(g(qb.w), gq),
+            );
+
+            let expected = generic_mat(ga * gb);
+            assert_eq!(mat(a * b), expected);
+            assert_eq!(mat(kernels::mat4_mul::<Fallback>(&a, &b)), expected);
+
+            let expected = ga * gv;
+            let expected = bits(&[expected.x.re, expected.y.re, expected.z.re, expected.w.re]);
+            assert_eq!(vec4(a * v), expected);
+            assert_eq!(vec4(kernels::mat4_mul_vec4::<Fallback>(&a, v)), expected);
+
+            let expected = ga.inverse().map(generic_mat);
+            assert_eq!(a.inverse().map(mat), expected);
+            assert_eq!(kernels::mat4_inverse::<Fallback>(&a).map(mat), expected);
+
+            let expected = gp.dot(gq).re.to_bits();
+            assert_eq!(p.dot(q).to_bits(), expected);
+            assert_eq!(kernels::vec3_dot::<Fallback>(p, q).to_bits(), expected);
+
+            let expected = gv.dot(gw).re.to_bits();
+            assert_eq!(v.dot(w).to_bits(), expected);
+            assert_eq!(kernels::vec4_dot::<Fallback>(v, w).to_bits(), expected);
+
+            let expected = generic_vec3(gp.cross(gq));
+            assert_eq!(vec3(p.cross(q)), expected);
+            assert_eq!(vec3(kernels::vec3_cross::<Fallback>(p, q)), expected);
+
+            let product = gqa * gqb;
+            let expected = bits(&[product.w.re, product.v.x.re, product.v.y.re, product.v.z.re]);
+            assert_eq!(quat(qa * qb), expected);
+            assert_eq!(quat(kernels::quaternion_mul::<Fallback>(qa, qb)), expected);
+        }
+    }
+
+    #[test]
+    fn wide_vectors_match_scalar() {
+        let points: Vec<Vec3<f32>> = (0..11)
+            .map(|i| {
+                let t = i as f32;
+                Vec3::new(t.sin() * 3.0, t * 0.5 - 2.0, (t * 0.7).cos())
+            })
+            .collect();
+        let q = Quaternion::new(0.7, Vec3::new(1.0, -2.0, 0.5).normalized());
+        let m = Mat4::translation(Vec3::new(1.0, 2.0, -3.0)) * Mat4::rotation_y(0.4);
+
+        let wide = Vec3x8::from_slice(&points[3..]);
+        let other = Vec3x8::from_slice(&points);
+        let rotated = wide.rotate(q);
+        let transformed = m * wide;
+        let dots = wide.dot(other);
+        let crosses = wide.cross(other);
+        for lane in 0..Vec3x8::<f32>::LANES {
+            let (p, o) = (points[lane + 3], points[lane]);
+            assert_eq!(wide.extract(lane), p);
+            assert!((rotated.extract(lane) - q.rotate(p)).magnitude() < 1e-5);
+            let expected = m * p.extend(1.0);
+            assert!((transformed.extract(lane) - expected.truncate()).magnitude() < 1e-5);
+            assert!(near!(dots[lane], p.dot(o), 1e-5));
+            assert!((crosses.extract(lane) - p.cross(o)).magnitude() < 1e-5);
+        }
+
+        let mut out = [Vec3::default(); 4];
+        let mut four = Vec3x4::splat(Vec3::new(1.0, 2.0, 3.0));
+        four.replace(2, Vec3::new(-1.0, 0.0, 4.0));
+        (four + four * 2.0 - four).write_to_slice(&mut out);
+        assert_eq!(out[0], Vec3::new(2.0, 4.0, 6.0));
+        assert_eq!(out[2], Vec3::new(-2.0, 0.0, 8.0));
+        assert_eq!(<[Vec3<f32>; 4]>::from(four)[2], Vec3::new(-1.0, 0.0, 4.0));
+        assert_eq!(Vec3x4::from(<[Vec3<f32>; 4]>::from(four)), four);
+        assert_eq!(
+            Vec3x4::splat(Vec3::new(3.0, 4.0, 0.0_f32)).magnitude(),
+            [5.0; 4]
+        );
+    }
+
+    #[test]
+    fn batch_transforms_match_single() {
+        //long enough to be split in several tasks with the rayon feature
+        let points: Vec<Vec3<f64>> = (0..10_003)
+            .map(|i| {
+                let t = i as f64 * 0.01;
+                Vec3::new(t.sin() * 5.0, t.cos() - 1.0, t * 0.3 - 4.0)
+            })
+            .collect();
+        let m = Mat4::translation(Vec3::new(0.5, -1.0, 2.0)) * Mat4::rotation_x(0.8);
+        let q = Quaternion::new(1.3, Vec3::new(0.0, 1.0, 1.0).normalized());
+        let projection = Mat4::perspective(1.2, 1.5, 0.1, 100.0);
+
+        let mut transformed = points.clone();
+        m.transform_points(&mut transformed);
+        let mut vectors = points.clone();
+        m.transform_vectors(&mut vectors);
+        let mut rotated = points.clone();
+        q.rotate_slice(&mut rotated);
+        let mut projected = points.clone();
+        projection.project_slice(&mut projected);
+
+        for (i, &p) in points.iter().enumerate() {
+            assert!((transformed[i] - (m * p.extend(1.0)).truncate()).magnitude() < 1e-12);
+            assert!((vectors[i] - (m * p.extend(0.0)).truncate()).magnitude() < 1e-12);
+            assert!((rotated[i] - q.rotate(p)).magnitude() < 1e-12);
+            let clip = projection * p.extend(1.0);
+            assert!((projected[i] - clip.truncate() / clip.w).magnitude() < 1e-9);
+        }
+
+        let mut empty: [Vec3<f32>; 0] = [];
+        Mat4::rotation_z(1.0).transform_points(&mut empty);
+    }
+
+    #[test]
+    fn closed_form_inverses_agree() {
+        //the inverses from the cofactors, computed the slow way
+        fn adjugate3(m: &Mat3<f64>) -> Mat3<f64> {
+            let mut adj = Mat3::zero();
+            for i in 0..3 {
+                for j in 0..3 {
+                    adj[j][i] = m.cofactor(i, j);
+                }
+            }
+            adj * (1.0 / m.determinant())
+        }
+        fn adjugate4(m: &Mat4<f64>) -> Mat4<f64> {
+            let mut adj = Mat4::zero();
+            for i in 0..4 {
+                for j in 0..4 {
+                    adj[j][i] = m.cofactor(i, j);
+                }
+            }
+            adj * (1.0 / (0..4).map(|i| m[i][0] * m.cofactor(i, 0)).sum::<f64>())
+        }
+        fn close4(a: Mat4<f64>, b: Mat4<f64>) -> bool {
+            (0..4).all(|i| (0..4).all(|j| near!(a[i][j], b[i][j], 1e-10)))
+        }
+
+        let m2 = Mat2::new(3.0, 1.0, -2.0, 4.0_f64);
+        let inv2 = m2.inverse().unwrap();
+        assert_eq!(m2 * inv2, Mat2::new(1.0, 0.0, 0.0, 1.0));
+        assert!(Mat2::new(1.0, 2.0, 2.0, 4.0_f64).inverse().is_none());
+
+        let m3 = Mat3::new(2.0, -1.0, 0.5, 0.3, 4.0, 1.0, -1.5, 0.2, 3.0_f64);
+        assert!(mat3_close(m3.inverse().unwrap(), adjugate3(&m3), 1e-12));
+        assert!(mat3_close(
+            m3.inverse_transpose().unwrap(),
+            adjugate3(&m3).transpost(),
+            1e-12
+        ));
+        assert!(near!(
+            m3.determinant(),
+            (0..3).map(|i| m3[0][i] * m3.cofactor(0, i)).sum::<f64>(),
+            1e-12
+        ));
+        let rotation = Mat3::rotation(0.7, Vec3::new(1.0, 2.0, -1.0).normalized());
+        assert!(mat3_close(
+            rotation.inverse_orthonormal(),
+            rotation.inverse().unwrap(),
+            1e-12
+        ));
+        assert!(Mat3::new(1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 0.0, 1.0, 0.0_f64)
+            .inverse()
+            .is_none());
+
+        let m4 = Mat4::new(
+            2.0, 0.5, -1.0, 0.1, 0.3, 3.0, 0.2, -0.4, -1.0, 0.7, 4.0, 0.6, 1.5, -2.0, 0.8, 2.5_f64,
+        );
+        assert!(close4(m4.inverse().unwrap(), adjugate4(&m4)));
+        assert!(close4(
+            m4.inverse_transpose().unwrap(),
+            adjugate4(&m4).transpost()
+        ));
+        assert!(near!(
+            m4.determinant(),
+            (0..4).map(|i| m4[i][0] * m4.cofactor(i, 0)).sum::<f64>(),
+            1e-12
+        ));
+        assert!(Mat4::new(
+            1.0, 2.0, 3.0, 4.0, 0.5, 1.0, 0.0, 2.0, 2.0, 4.0, 6.0, 8.0, 0.0, 1.0, 1.0, 0.0_f64
+        )
+        .inverse()
+        .is_none());
+
+        //the 2x2 minors give the same inverse as the cofactors on less friendly matrices too
+        let mut seed = 0x9e37_79b9_u32;
+        let mut next = || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            (seed as f64 / u32::MAX as f64) * 8.0 - 4.0
+        };
+        for _ in 0..100 {
+            let m = Mat4::from([[0.0; 4]; 4].map(|c: [f64; 4]| c.map(|_| next())));
+            let inv = m.inverse().unwrap();
+            assert!(close4(inv, adjugate4(&m)));
+            assert!(close4(m * inv, Mat4::default()));
+        }
+
+        let rigid = Mat4::translation(Vec3::new(1.0, -2.0, 3.0)) * Mat4::rotation_y(1.1);
+        assert!(close4(
+            rigid.inverse_orthonormal(),
+            rigid.inverse().unwrap()
+        ));
+        let affine = rigid * Mat4::shearing_xy(0.3, -0.2) * Mat4::scale(2.0);
+        assert!(close4(
+            affine.inverse_affine().unwrap(),
+            affine.inverse().unwrap()
+        ));
+        assert!(close4(affine.inverse_affine().unwrap(), adjugate4(&affine)));
+        assert!(Mat4::scale(0.0_f64).inverse_affine().is_none());
+        assert!(Mat4::<f64>::zero().inverse().is_none());
+    }
+
+    #[test]
+    fn precision_casts() {
+        let m = Mat3::new(1.5, -2.0, 3.25, 0.0, 1.0, 2.0, -7.5, 8.0, 9.0_f64);
+        assert_eq!(
+            m.cast::<i32>(),
+            Some(Mat3::new(1, -2, 3, 0, 1, 2, -7, 8, 9))
+        );
+        assert_eq!(m.cast::<u8>(), None);
+        assert_eq!(
+            Mat2::new(1, 2, 3, 4_i32).map(|x| x * 2),
+            Mat2::new(2, 4, 6, 8)
+        );
+        assert_eq!(Mat4::<f64>::from(Mat4::scale(2.0_f32)), Mat4::scale(2.0));
+
+        let q = Quaternion::new(0.3, Vec3::new(1.0, 2.0, 3.0_f32));
+        let wide = Quaternion::<f64>::from(q);
+        assert_eq!(wide.cast::<f32>().map(|q| (q.w, q.v)), Some((q.w, q.v)));
+        assert_eq!(q.cast::<f64>().map(|q| (q.w, q.v)), Some((wide.w, wide.v)));
+        let e = Euler::new(0.1, 0.2, 0.3_f32);
+        assert_eq!(Euler::<f64>::from(e).cast::<f32>(), Some(e));
+
+        assert_eq!(
+            Vec3::<f64>::from(Vec3::new(0.1_f32, 2.0, -3.0)).x,
+            0.1_f32 as f64
+        );
+        assert_eq!(
+            Vec2::<f64>::from(Vec2::new(-3_i32, 4)),
+            Vec2::new(-3.0, 4.0)
+        );
+        assert_eq!(
+            Mat2::<f32>::from(Mat2::new(1_u16, 2, 3, 4)),
+            Mat2::new(1.0, 2.0, 3.0, 4.0)
+        );
+
+        //a few centimeters from a camera ten thousand kilometers away from the origin
+        let origin = Vec3::new(1.0e7, -2.0e7, 5.0e6);
+        let position = origin + Vec3::new(0.031, -0.012, 0.007);
+        let relative = position.camera_relative(origin);
+        assert!((relative - Vec3::new(0.031, -0.012, 0.007)).magnitude() < 1e-6);
+        let naive = position.map(|x| x as f32) - origin.map(|x| x as f32);
+        assert!((naive - Vec3::new(0.031, -0.012, 0.007)).magnitude() > 1e-3);
+
+        let world = Mat4::translation(position) * Mat4::rotation_z(0.5);
+        let view = world.camera_relative(origin);
+        let local = Vec4::new(0.01, 0.02, 0.0, 1.0);
+        let expected = (world * local.map(f64::from)).truncate() - origin;
+        let p = (view * local).truncate();
+        assert!((Vec3::<f64>::from(p) - expected).magnitude() < 1e-6);
+    }
+
+    #[test]
+    fn display_formatting() {
+        let v = Vec3::new(1.0, 2.5, -3.0);
+        assert_eq!(format!("{}", v), "(1, 2.5, -3)");
+        assert_eq!(format!("{:.2}", v), "(1.00, 2.50, -3.00)");
+        assert_eq!(
+            format!("{:8.3}", Vec2::new(1.0, -2.0)),
+            "(   1.000,   -2.000)"
+        );
+        assert_eq!(format!("{:+}", Vec2::new(1.0, -2.0)), "(+1, -2)");
+        assert_eq!(format!("{:e}", Vec2::new(1500.0, 0.25)), "(1.5e3, 2.5e-1)");
+        assert_eq!(format!("{}", Vec4::new(1, 2, 3, 4)), "(1, 2, 3, 4)");
+
+        //the translation is the last column, as on paper
+        let m = Mat3::new(1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 5.0, -20.5, 1.0);
+        assert_eq!(
+            format!("{:.1}", m),
+            "[1.0, 0.0,   5.0]\n[0.0, 1.0, -20.5]\n[0.0, 0.0,   1.0]"
+        );
+        assert_eq!(
+            format!("{}", Mat2::new(1, -20, 300, 4)),
+            "[  1, 300]\n[-20,   4]"
+        );
+        assert_eq!(
+            format!("{:.1e}", Mat2::new(1000.0, 0.0, 0.0, 0.001)),
+            "[1.0e3,  0.0e0]\n[0.0e0, 1.0e-3]"
+        );
+        assert_eq!(
+            format!("{:4}", Mat4::<i32>::zero()).lines().next(),
+            Some("[   0,    0,    0,    0]")
+        );
+
+        let q = Quaternion::new_sv(1.0, Vec3::new(2.0, -3.0, 0.5));
+        assert_eq!(format!("{}", q), "1 + 2i - 3j + 0.5k");
+        assert_eq!(format!("{:.1}", q), "1.0 + 2.0i - 3.0j + 0.5k");
+        assert_eq!(format!("{}", Complex::new(1.5, -2.0)), "1.5 - 2i");
+        assert_eq!(format!("{}", Complex::new(1.0, -0.0)), "1 - 0i");
+        assert_eq!(format!("{:.1}", Complex::new(1.0, -0.01)), "1.0 - 0.0i");
+        assert_eq!(format!("{}", Complex::new(2, -3)), "2 - 3i");
+        assert_eq!(format!("{:e}", Complex::new(-100.0, 0.5)), "-1e2 + 5e-1i");
+        assert_eq!(
+            format!("{}", Rotor3::new(0.5, Bivector3::new(1.0, -1.0, 0.0))),
+            "0.5 + 1 e12 - 1 e23 + 0 e31"
+        );
+        assert_eq!(format!("{:.1}", Rotor2::new(0.0_f64)), "1.0 + 0.0 e12");
+        assert_eq!(
+            format!("{:.1}", Euler::new(0.1, 0.2, 0.3)),
+            "(yaw: 0.1, pitch: 0.2, roll: 0.3)"
+        );
+        assert_eq!(
+            format!("{}", DualN::new(2.0, [1.0, -0.5])),
+            "2 + (1, -0.5)ε"
+        );
+    }
+
+    //without `std` num-traits takes every float function from libm, check the crate still gets
+    //the same answers through it
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn libm_float_math() {
+        let axis = Vec3::new(1.0, 2.0, -2.0_f64).normalized();
+        assert!(near!(axis.magnitude(), 1.0, 1e-15));
+        assert!(near!(axis.x, 1.0 / 3.0, 1e-15));
+
+        let r = Mat3::rotation(0.8, axis);
+        assert!(r.is_orthonormal(1e-12));
+        assert!(near!(r.determinant(), 1.0, 1e-12));
+        assert!(((r * axis) - axis).magnitude() < 1e-12);
+
+        let w = axis * 0.8;
+        assert!(mat3_close(SO3::exp(w).matrix(), r, 1e-12));
+        assert!((SO3::exp(w).log() - w).magnitude() < 1e-12);
+
+        let m = Mat4::translation(Vec3::new(1.0, -2.0, 3.0)) * Mat4::scale(2.0_f64);
+        let identity = m * m.inverse().unwrap();
+        for i in 0..4 {
+            for j in 0..4 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!(near!(identity[i][j], expected, 1e-12));
+            }
+        }
+
+        let d = Dual::variable(4.0_f64, 0).sqrt();
+        assert_eq!((d.re, d.derivative()), (2.0, 0.25));
+    }
 }
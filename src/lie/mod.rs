@@ -0,0 +1,10 @@
+//! Lie groups of 3D rotations and rigid transforms, with their tangent spaces.
+//!
+//! Tangent vectors are perturbations on the right: `x.boxplus(d) = x * exp(d)` and
+//! `y.boxminus(x) = log(x^-1 * y)`.
+
+pub mod se3;
+pub mod so3;
+
+pub use se3::SE3;
+pub use so3::SO3;
@@ -0,0 +1,218 @@
+//! The group of rigid transforms and its tangent space se(3).
+//!
+//! Tangent vectors are twists split as `(rho, phi)`: `rho` is the linear part and `phi` the
+//! rotation vector. 6x6 matrices acting on twists are column major `[[T; 6]; 6]` arrays, with
+//! `rho` in the first three rows and columns.
+
+use num_traits::cast;
+use num_traits::identities;
+
+use core::ops::Mul;
+
+use crate::lie::so3::{self, SO3};
+use crate::matrix::{Mat3, Mat4};
+use crate::quaternions::Quaternion;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+///The 4x4 matrix form of the twist `(rho, phi)`, as taken by `Mat4::exp`
+pub fn hat<T: FloatScalar>(rho: Vec3<T>, phi: Vec3<T>) -> Mat4<T> {
+    let skew = so3::hat(phi);
+    let mut ret = Mat4::zero();
+
+    for i in 0..3 {
+        ret[i][..3].clone_from_slice(&skew[i][..3]);
+    }
+    ret[3][0] = rho.x;
+    ret[3][1] = rho.y;
+    ret[3][2] = rho.z;
+    ret
+}
+
+///Inverse of `hat`, returning `(rho, phi)`
+pub fn vee<T: FloatScalar>(m: Mat4<T>) -> (Vec3<T>, Vec3<T>) {
+    (
+        Vec3::new(m[3][0], m[3][1], m[3][2]),
+        Vec3::new(m[1][2], m[2][0], m[0][1]),
+    )
+}
+
+///Left Jacobian of SE(3), built from the one of SO(3) and the coupling block given in Barfoot,
+///"State Estimation for Robotics"
+pub fn left_jacobian<T: FloatScalar>(rho: Vec3<T>, phi: Vec3<T>) -> [[T; 6]; 6] {
+    let j = so3::left_jacobian(phi);
+    blocks(j, coupling(rho, phi), Mat3::zero(), j)
+}
+
+///Right Jacobian of SE(3)
+#[inline]
+pub fn right_jacobian<T: FloatScalar>(rho: Vec3<T>, phi: Vec3<T>) -> [[T; 6]; 6] {
+    left_jacobian(-rho, -phi)
+}
+
+//the upper right block Q of the left Jacobian
+fn coupling<T: FloatScalar>(rho: Vec3<T>, phi: Vec3<T>) -> Mat3<T> {
+    let one = identities::one::<T>();
+    let two = one + one;
+    let three = two + one;
+    let theta = phi.magnitude();
+
+    let p = so3::hat(phi);
+    let r = so3::hat(rho);
+    let pr = p * r;
+    let rp = r * p;
+    let prp = pr * p;
+    let pp = p * p;
+
+    let (a, b, c) = if theta <= T::epsilon().powf(one / cast::cast::<f64, T>(8.0).unwrap()) {
+        //the closed forms cancel out catastrophically for small angles, use their series
+        let theta2 = theta * theta;
+        let series = |c0: f64, c2: f64, c4: f64| {
+            cast::cast::<f64, T>(c0).unwrap()
+                + theta2
+                    * (cast::cast::<f64, T>(c2).unwrap()
+                        + theta2 * cast::cast::<f64, T>(c4).unwrap())
+        };
+        (
+            series(1.0 / 6.0, -1.0 / 120.0, 1.0 / 5040.0),
+            series(1.0 / 24.0, -1.0 / 720.0, 1.0 / 40320.0),
+            series(1.0 / 120.0, -1.0 / 2520.0, 1.0 / 120960.0),
+        )
+    } else {
+        let (sin, cos) = (theta.sin(), theta.cos());
+        let theta2 = theta * theta;
+        let theta3 = theta2 * theta;
+        (
+            (theta - sin) / theta3,
+            (theta2 + two * cos - two) / (two * theta2 * theta2),
+            (two * theta - three * sin + theta * cos) / (two * theta3 * theta2),
+        )
+    };
+
+    r * (one / two)
+        + (pr + rp + prp) * a
+        + (pp * r + rp * p - prp * three) * b
+        + (prp * p + pp * r * p) * c
+}
+
+//assembles [[a, b], [c, d]], given block by block row
+fn blocks<T: FloatScalar>(a: Mat3<T>, b: Mat3<T>, c: Mat3<T>, d: Mat3<T>) -> [[T; 6]; 6] {
+    let mut ret = [[identities::zero::<T>(); 6]; 6];
+
+    for i in 0..3 {
+        for j in 0..3 {
+            ret[j][i] = a[j][i];
+            ret[j + 3][i] = b[j][i];
+            ret[j][i + 3] = c[j][i];
+            ret[j + 3][i + 3] = d[j][i];
+        }
+    }
+    ret
+}
+
+///A rigid transform: a rotation followed by a translation
+#[derive(Debug, Copy, Clone)]
+pub struct SE3<T: FloatScalar> {
+    pub rotation: SO3<T>,
+    pub translation: Vec3<T>,
+}
+
+impl<T: FloatScalar> SE3<T> {
+    ///Constructs the transform rotating by `rotation`, which gets normalized, and then moving by
+    ///`translation`
+    #[inline]
+    pub fn new(rotation: Quaternion<T>, translation: Vec3<T>) -> Self {
+        SE3 {
+            rotation: SO3::from_quaternion(rotation),
+            translation,
+        }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        SE3 {
+            rotation: SO3::identity(),
+            translation: Vec3::default(),
+        }
+    }
+
+    ///The transform reached by following the twist `(rho, phi)` for one unit of time
+    pub fn exp(rho: Vec3<T>, phi: Vec3<T>) -> Self {
+        SE3 {
+            rotation: SO3::exp(phi),
+            translation: so3::left_jacobian(phi) * rho,
+        }
+    }
+
+    ///The twist `(rho, phi)` of `self`
+    pub fn log(&self) -> (Vec3<T>, Vec3<T>) {
+        let phi = self.rotation.log();
+        (so3::left_jacobian_inverse(phi) * self.translation, phi)
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.inverse();
+        SE3 {
+            rotation,
+            translation: -rotation.rotate(self.translation),
+        }
+    }
+
+    #[inline]
+    pub fn transform_point(&self, p: Vec3<T>) -> Vec3<T> {
+        self.rotation.rotate(p) + self.translation
+    }
+
+    #[inline]
+    pub fn transform_vector(&self, v: Vec3<T>) -> Vec3<T> {
+        self.rotation.rotate(v)
+    }
+
+    pub fn matrix(&self) -> Mat4<T> {
+        let rotation = self.rotation.matrix();
+        let mut ret = Mat4::translation(self.translation);
+
+        for i in 0..3 {
+            ret[i][..3].clone_from_slice(&rotation[i][..3]);
+        }
+        ret
+    }
+
+    ///Maps twists at `self` to the identity: `self * exp(d) == exp(adjoint * d) * self`
+    pub fn adjoint(&self) -> [[T; 6]; 6] {
+        let r = self.rotation.matrix();
+        blocks(r, so3::hat(self.translation) * r, Mat3::zero(), r)
+    }
+
+    ///`self` perturbed by the twist `(rho, phi)`, expressed in the local frame
+    #[inline]
+    pub fn boxplus(&self, rho: Vec3<T>, phi: Vec3<T>) -> Self {
+        *self * SE3::exp(rho, phi)
+    }
+
+    ///The twist `(rho, phi)` such that `other.boxplus(rho, phi) == self`
+    #[inline]
+    pub fn boxminus(&self, other: &SE3<T>) -> (Vec3<T>, Vec3<T>) {
+        (other.inverse() * *self).log()
+    }
+}
+
+impl<T: FloatScalar> Mul<SE3<T>> for SE3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: SE3<T>) -> Self {
+        SE3 {
+            rotation: self.rotation * other.rotation,
+            translation: self.transform_point(other.translation),
+        }
+    }
+}
+
+impl<T: FloatScalar> From<SE3<T>> for Mat4<T> {
+    #[inline]
+    fn from(transform: SE3<T>) -> Self {
+        transform.matrix()
+    }
+}
@@ -0,0 +1,275 @@
+//! The group of 3D rotations and its tangent space so(3), where an element is the rotation vector
+//! `axis * angle`.
+
+use num_traits::cast;
+use num_traits::identities;
+
+use core::ops::Mul;
+
+use crate::matrix::Mat3;
+use crate::quaternions::Quaternion;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+///The skew-symmetric matrix of the cross product by `w`: `hat(w) * v == w.cross(v)`
+pub fn hat<T: FloatScalar>(w: Vec3<T>) -> Mat3<T> {
+    let zero = identities::zero::<T>();
+    Mat3::new(zero, w.z, -w.y, -w.z, zero, w.x, w.y, -w.x, zero)
+}
+
+///Inverse of `hat`. Only the lower triangle of `m` is read
+pub fn vee<T: FloatScalar>(m: Mat3<T>) -> Vec3<T> {
+    Vec3::new(m[1][2], -m[0][2], m[0][1])
+}
+
+///Rotation matrix of `|w|` radians about `w` (Rodrigues' formula)
+pub fn exp<T: FloatScalar>(w: Vec3<T>) -> Mat3<T> {
+    let half = identities::one::<T>() / (identities::one::<T>() + identities::one::<T>());
+    let theta = w.magnitude();
+
+    if theta <= T::epsilon().sqrt() {
+        //second order Taylor expansion, exact to machine precision at this size
+        let skew = hat(w);
+        Mat3::default() + skew + skew * skew * half
+    } else {
+        Mat3::rotation(theta, w / theta)
+    }
+}
+
+///Rotation vector of the rotation matrix `r`, with an angle in `[0, pi]`
+pub fn log<T: FloatScalar>(r: Mat3<T>) -> Vec3<T> {
+    let one = identities::one::<T>();
+    let two = one + one;
+    let half = one / two;
+    let half_pi = cast::cast::<f64, T>(core::f64::consts::FRAC_PI_2).unwrap();
+
+    let cos = ((r[0][0] + r[1][1] + r[2][2] - one) / two)
+        .max(-one)
+        .min(one);
+    //(R - R^T) / 2 = sin(theta) * hat(axis)
+    let skew = vee((r - r.transpost()) * half);
+    //acos loses half the digits close to a half turn, atan2 doesn't
+    let theta = skew.magnitude().atan2(cos);
+
+    if theta <= T::epsilon().sqrt() {
+        skew
+    } else if theta < half_pi {
+        skew * (theta / theta.sin())
+    } else {
+        //sin(theta) vanishes near pi, read the axis from the symmetric part instead:
+        //(R + R^T) / 2 - cos(theta) * I = (1 - cos(theta)) * axis * axis^T
+        let sym = (r + r.transpost()) * half;
+        let diag = Vec3::new(sym[0][0] - cos, sym[1][1] - cos, sym[2][2] - cos);
+        let k = if diag.x >= diag.y && diag.x >= diag.z {
+            0
+        } else if diag.y >= diag.z {
+            1
+        } else {
+            2
+        };
+
+        let mut column = Vec3::from(sym[k]);
+        column[k] -= cos;
+        let mut axis = column.normalized();
+        if axis.dot(skew) < identities::zero::<T>() {
+            axis = -axis;
+        }
+        axis * theta
+    }
+}
+
+///Left Jacobian of SO(3): `exp(w + d) ~= exp(left_jacobian(w) * d) * exp(w)` for a small `d`
+pub fn left_jacobian<T: FloatScalar>(w: Vec3<T>) -> Mat3<T> {
+    let one = identities::one::<T>();
+    let two = one + one;
+    let six = cast::cast::<f64, T>(6.0).unwrap();
+    let theta = w.magnitude();
+    let skew = hat(w);
+
+    let (a, b) = if theta <= T::epsilon().sqrt() {
+        (one / two, one / six)
+    } else {
+        let theta2 = theta * theta;
+        let half_sin = (theta / two).sin();
+        //1 - cos(theta) written without the cancellation
+        (
+            two * half_sin * half_sin / theta2,
+            (theta - theta.sin()) / (theta2 * theta),
+        )
+    };
+
+    Mat3::default() + skew * a + skew * skew * b
+}
+
+///Inverse of `left_jacobian`, singular when the angle reaches a full turn
+pub fn left_jacobian_inverse<T: FloatScalar>(w: Vec3<T>) -> Mat3<T> {
+    let one = identities::one::<T>();
+    let two = one + one;
+    let twelve = cast::cast::<f64, T>(12.0).unwrap();
+    let theta = w.magnitude();
+    let skew = hat(w);
+
+    let b = if theta <= T::epsilon().sqrt() {
+        one / twelve
+    } else {
+        one / (theta * theta) - (one + theta.cos()) / (two * theta * theta.sin())
+    };
+
+    Mat3::default() - skew * (one / two) + skew * skew * b
+}
+
+///Right Jacobian of SO(3): `exp(w + d) ~= exp(w) * exp(right_jacobian(w) * d)` for a small `d`
+#[inline]
+pub fn right_jacobian<T: FloatScalar>(w: Vec3<T>) -> Mat3<T> {
+    left_jacobian(-w)
+}
+
+///Inverse of `right_jacobian`
+#[inline]
+pub fn right_jacobian_inverse<T: FloatScalar>(w: Vec3<T>) -> Mat3<T> {
+    left_jacobian_inverse(-w)
+}
+
+///A 3D rotation, stored as a unit Quaternion
+#[derive(Debug, Copy, Clone)]
+pub struct SO3<T: FloatScalar> {
+    quat: Quaternion<T>,
+}
+
+impl<T: FloatScalar> SO3<T> {
+    #[inline]
+    pub fn identity() -> Self {
+        SO3 {
+            quat: Quaternion::new_sv(identities::one::<T>(), Vec3::default()),
+        }
+    }
+
+    ///Wraps a rotation Quaternion, normalizing it
+    #[inline]
+    pub fn from_quaternion(quat: Quaternion<T>) -> Self {
+        SO3 {
+            quat: quat * (identities::one::<T>() / quat.magnitude()),
+        }
+    }
+
+    ///Wraps a rotation matrix. `rotation` must be orthonormal with a positive determinant
+    #[inline]
+    pub fn from_matrix(rotation: Mat3<T>) -> Self {
+        SO3::from_quaternion(Quaternion::from(rotation))
+    }
+
+    ///Rotation of `|w|` radians about `w`
+    pub fn exp(w: Vec3<T>) -> Self {
+        let one = identities::one::<T>();
+        let two = one + one;
+        let theta = w.magnitude();
+
+        let (cos, k) = if theta <= T::epsilon().sqrt() {
+            let theta2 = theta * theta;
+            let eight = cast::cast::<f64, T>(8.0).unwrap();
+            let forty_eight = cast::cast::<f64, T>(48.0).unwrap();
+            (one - theta2 / eight, one / two - theta2 / forty_eight)
+        } else {
+            let half = theta / two;
+            (half.cos(), half.sin() / theta)
+        };
+
+        SO3 {
+            quat: Quaternion::new_sv(cos, w * k),
+        }
+    }
+
+    ///Rotation vector of `self`, with an angle in `[0, pi]`
+    pub fn log(&self) -> Vec3<T> {
+        let two = identities::one::<T>() + identities::one::<T>();
+
+        //q and -q are the same rotation, take the one with the shortest angle
+        let q = if self.quat.w < identities::zero::<T>() {
+            -self.quat
+        } else {
+            self.quat
+        };
+        let n = q.v.magnitude();
+
+        if n <= T::epsilon().sqrt() {
+            q.v * (two / q.w)
+        } else {
+            q.v * (two * n.atan2(q.w) / n)
+        }
+    }
+
+    #[inline]
+    pub fn quaternion(&self) -> Quaternion<T> {
+        self.quat
+    }
+
+    #[inline]
+    pub fn matrix(&self) -> Mat3<T> {
+        Mat3::from(self.quat)
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        SO3 {
+            quat: self.quat.conjugate(),
+        }
+    }
+
+    #[inline]
+    pub fn rotate(&self, v: Vec3<T>) -> Vec3<T> {
+        self.quat.rotate(v)
+    }
+
+    ///Maps tangent vectors at `self` to the identity: `self * exp(d) == exp(adjoint * d) * self`.
+    ///For rotations this is the rotation matrix itself
+    #[inline]
+    pub fn adjoint(&self) -> Mat3<T> {
+        self.matrix()
+    }
+
+    ///`self` perturbed by the tangent vector `d`, expressed in the local frame
+    #[inline]
+    pub fn boxplus(&self, d: Vec3<T>) -> Self {
+        *self * SO3::exp(d)
+    }
+
+    ///The tangent vector `d` such that `other.boxplus(d) == self`
+    #[inline]
+    pub fn boxminus(&self, other: &SO3<T>) -> Vec3<T> {
+        (other.inverse() * *self).log()
+    }
+}
+
+impl<T: FloatScalar> Mul<SO3<T>> for SO3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: SO3<T>) -> Self {
+        SO3 {
+            quat: self.quat * other.quat,
+        }
+    }
+}
+
+impl<T: FloatScalar> Mul<Vec3<T>> for SO3<T> {
+    type Output = Vec3<T>;
+
+    #[inline]
+    fn mul(self, v: Vec3<T>) -> Vec3<T> {
+        self.rotate(v)
+    }
+}
+
+impl<T: FloatScalar> From<SO3<T>> for Mat3<T> {
+    #[inline]
+    fn from(rotation: SO3<T>) -> Self {
+        rotation.matrix()
+    }
+}
+
+impl<T: FloatScalar> From<SO3<T>> for Quaternion<T> {
+    #[inline]
+    fn from(rotation: SO3<T>) -> Self {
+        rotation.quat
+    }
+}
@@ -0,0 +1,79 @@
+use num_traits::identities;
+
+use crate::FloatScalar;
+
+const MAX_SWEEPS: usize = 64;
+
+///Cyclic Jacobi eigen decomposition of the symmetric matrix `a`.
+///
+///Returns the eigenvalues and a matrix whose `i`-th column (`vectors[i]`) is the unit eigenvector
+///of the `i`-th eigenvalue. Neither is sorted.
+#[allow(clippy::needless_range_loop)]
+pub(crate) fn jacobi_eigen<T: FloatScalar, const N: usize>(
+    mut a: [[T; N]; N],
+) -> ([T; N], [[T; N]; N]) {
+    let zero = identities::zero::<T>();
+    let one = identities::one::<T>();
+    let two = one + one;
+
+    //columns of `v`, stored as rows so each eigenvector is a contiguous array
+    let mut v = [[zero; N]; N];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = one;
+    }
+
+    for _ in 0..MAX_SWEEPS {
+        let mut off = zero;
+        let mut total = zero;
+        for i in 0..N {
+            for j in 0..N {
+                let sq = a[i][j] * a[i][j];
+                total += sq;
+                if i != j {
+                    off += sq;
+                }
+            }
+        }
+
+        if off <= T::epsilon() * T::epsilon() * total {
+            break;
+        }
+
+        for p in 0..N {
+            for q in p + 1..N {
+                if a[p][q] == zero {
+                    continue;
+                }
+
+                let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + one).sqrt());
+                let c = one / (t * t + one).sqrt();
+                let s = t * c;
+
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+
+                for k in 0..N {
+                    let (pk, qk) = (a[p][k], a[q][k]);
+                    a[p][k] = c * pk - s * qk;
+                    a[q][k] = s * pk + c * qk;
+                }
+
+                let (vp, vq) = (v[p], v[q]);
+                for k in 0..N {
+                    v[p][k] = c * vp[k] - s * vq[k];
+                    v[q][k] = s * vp[k] + c * vq[k];
+                }
+            }
+        }
+    }
+
+    let mut values = [zero; N];
+    for (i, value) in values.iter_mut().enumerate() {
+        *value = a[i][i];
+    }
+    (values, v)
+}
@@ -0,0 +1,217 @@
+use num_traits::cast;
+use num_traits::identities;
+
+use crate::lie::so3::{self, hat, left_jacobian, vee};
+use crate::matrix::{Mat3, Mat4};
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+//degree of the diagonal Pade approximant, accurate to double precision once the norm is below 1/2
+const PADE_DEGREE: usize = 6;
+const MAX_SQUARE_ROOTS: usize = 64;
+const MAX_SERIES_TERMS: usize = 64;
+
+impl<T: FloatScalar> Mat3<T> {
+    ///Matrix exponential.
+    ///
+    ///Skew-symmetric matrices (elements of so(3)) take the closed form Rodrigues path and always
+    ///give an exact rotation, anything else goes through Pade scaling-and-squaring.
+    pub fn exp(&self) -> Self {
+        if is_skew_symmetric(self) {
+            so3::exp(vee(*self))
+        } else {
+            pade_exp(self)
+        }
+    }
+
+    ///Principal matrix logarithm, the inverse of `exp`.
+    ///
+    ///Rotations get the closed form answer, a skew-symmetric matrix whose axis-angle is at most
+    ///pi. Other matrices use inverse scaling-and-squaring. Returns `None` if the matrix has no
+    ///real logarithm, which is always the case when its determinant isn't positive.
+    pub fn log(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det <= identities::zero::<T>() {
+            return None;
+        }
+
+        let tol = T::epsilon().sqrt();
+        if (det - identities::one::<T>()).abs() <= tol && self.is_orthonormal(tol) {
+            Some(hat(so3::log(*self)))
+        } else {
+            general_log(self)
+        }
+    }
+}
+
+impl<T: FloatScalar> Mat4<T> {
+    ///Exponential of an se(3) twist: a skew-symmetric angular velocity in the top left block and
+    ///the linear velocity in the last column. The bottom row is ignored.
+    ///
+    ///Returns the rigid transform reached by moving along the twist for one unit of time.
+    pub fn exp(&self) -> Self {
+        let omega = Vec3::new(self[1][2], self[2][0], self[0][1]);
+        let v = Vec3::new(self[3][0], self[3][1], self[3][2]);
+
+        let rotation = so3::exp(omega);
+        let t = left_jacobian(omega) * v;
+
+        rigid(rotation, t)
+    }
+
+    ///Logarithm of a rigid transform, returned as an se(3) twist matrix with a zero bottom row.
+    ///
+    ///Returns `None` if `self` isn't a rotation followed by a translation.
+    pub fn log(&self) -> Option<Self> {
+        let zero = identities::zero::<T>();
+        let one = identities::one::<T>();
+        let tol = T::epsilon().sqrt();
+
+        let rotation = Mat3::new(
+            self[0][0], self[0][1], self[0][2], self[1][0], self[1][1], self[1][2], self[2][0],
+            self[2][1], self[2][2],
+        );
+        let bottom = [self[0][3], self[1][3], self[2][3], self[3][3] - one];
+        if bottom.iter().any(|x| x.abs() > tol)
+            || !rotation.is_orthonormal(tol)
+            || rotation.determinant() < zero
+        {
+            return None;
+        }
+
+        let omega = so3::log(rotation);
+        let v = left_jacobian(omega).inverse()? * Vec3::new(self[3][0], self[3][1], self[3][2]);
+
+        let mut ret = Mat4::zero();
+        let skew = hat(omega);
+        for i in 0..3 {
+            ret[i][..3].clone_from_slice(&skew[i][..3]);
+        }
+        ret[3][0] = v.x;
+        ret[3][1] = v.y;
+        ret[3][2] = v.z;
+        Some(ret)
+    }
+}
+
+pub(crate) fn rigid<T: FloatScalar>(rotation: Mat3<T>, t: Vec3<T>) -> Mat4<T> {
+    let mut ret = Mat4::translation(t);
+    for i in 0..3 {
+        ret[i][..3].clone_from_slice(&rotation[i][..3]);
+    }
+    ret
+}
+
+fn is_skew_symmetric<T: FloatScalar>(m: &Mat3<T>) -> bool {
+    let sym = *m + m.transpost();
+    let tol = T::epsilon() * (norm_1(m) + identities::one::<T>());
+
+    (0..3).all(|i| (0..3).all(|j| sym[i][j].abs() <= tol))
+}
+
+//maximum absolute column sum
+fn norm_1<T: FloatScalar>(m: &Mat3<T>) -> T {
+    (0..3).fold(identities::zero::<T>(), |acc, i| {
+        acc.max(m[i][0].abs() + m[i][1].abs() + m[i][2].abs())
+    })
+}
+
+//scaling-and-squaring with a diagonal Pade approximant (Moler and Van Loan, method 3)
+fn pade_exp<T: FloatScalar>(m: &Mat3<T>) -> Mat3<T> {
+    let one = identities::one::<T>();
+    let two = one + one;
+    let half = one / two;
+
+    let mut squarings = 0;
+    let mut scale = one;
+    let norm = norm_1(m);
+    while norm.is_finite() && norm * scale > half {
+        scale /= two;
+        squarings += 1;
+    }
+    let a = *m * scale;
+
+    let q = cast::cast::<usize, T>(PADE_DEGREE).unwrap();
+    let mut c = one;
+    let mut power = Mat3::default();
+    let mut numerator = Mat3::default();
+    let mut denominator = Mat3::default();
+    for k in 1..=PADE_DEGREE {
+        let kt = cast::cast::<usize, T>(k).unwrap();
+        c = c * (q - kt + one) / (kt * (two * q - kt + one));
+        power = power * a;
+
+        numerator += power * c;
+        if k % 2 == 0 {
+            denominator += power * c;
+        } else {
+            denominator -= power * c;
+        }
+    }
+
+    //the denominator is always invertible for such a small norm
+    let mut ret = denominator.inverse().unwrap() * numerator;
+    for _ in 0..squarings {
+        ret = ret * ret;
+    }
+    ret
+}
+
+//inverse scaling-and-squaring: take square roots until close to the identity, then use the
+//series log(A) = 2 * atanh((A - I) * (A + I)^-1)
+fn general_log<T: FloatScalar>(m: &Mat3<T>) -> Option<Mat3<T>> {
+    let one = identities::one::<T>();
+    let two = one + one;
+    let quarter = one / (two * two);
+    let identity = Mat3::default();
+
+    let mut a = *m;
+    let mut factor = one;
+    let mut roots = 0;
+    while norm_1(&(a - identity)) > quarter {
+        if roots == MAX_SQUARE_ROOTS {
+            return None;
+        }
+        a = sqrt(&a)?;
+        factor *= two;
+        roots += 1;
+    }
+
+    let z = (a - identity) * (a + identity).inverse()?;
+    let z2 = z * z;
+    let mut term = z;
+    let mut sum = z;
+    for k in 1..MAX_SERIES_TERMS {
+        term = term * z2;
+        let next = term * (one / cast::cast::<usize, T>(2 * k + 1).unwrap());
+        sum += next;
+        if norm_1(&next) <= T::epsilon() * norm_1(&sum) {
+            break;
+        }
+    }
+
+    Some(sum * (two * factor))
+}
+
+//principal square root with the Denman-Beavers iteration
+fn sqrt<T: FloatScalar>(m: &Mat3<T>) -> Option<Mat3<T>> {
+    let half = identities::one::<T>() / (identities::one::<T>() + identities::one::<T>());
+    let mut y = *m;
+    let mut z = Mat3::default();
+
+    for _ in 0..MAX_SQUARE_ROOTS {
+        let next_y = (y + z.inverse()?) * half;
+        let next_z = (z + y.inverse()?) * half;
+        let delta = norm_1(&(next_y - y));
+
+        y = next_y;
+        z = next_z;
+        //convergence is quadratic, the error of the new iterate is about the square of `delta`
+        if delta <= T::epsilon().sqrt() * norm_1(&y) {
+            return Some(y);
+        }
+    }
+
+    //did not converge, the matrix likely has negative real eigenvalues
+    None
+}
@@ -0,0 +1,146 @@
+use num_traits::cast;
+use num_traits::identities;
+
+use crate::matrix::eigen::jacobi_eigen;
+use crate::matrix::Mat3;
+use crate::vectors::{Vec2, Vec3};
+use crate::FloatScalar;
+
+impl<T: FloatScalar> Mat3<T> {
+    ///Computes the projective transform mapping each point of `src` to the point of `dst` with the
+    ///same index, to be used with `transform_point2`.
+    ///
+    ///Returns `None` if three points of either quad are collinear.
+    pub fn homography_from_points(src: [Vec2<T>; 4], dst: [Vec2<T>; 4]) -> Option<Self> {
+        let from = projective_basis(src)?;
+        let to = projective_basis(dst)?;
+
+        Some(normalized(to * from.inverse()?))
+    }
+
+    ///Estimates the projective transform that best maps `src` onto `dst` with the normalized
+    ///Direct Linear Transform. Needs at least 4 correspondences.
+    ///
+    ///Returns `None` if the slices have different lengths or fewer than 4 points.
+    pub fn homography_dlt(src: &[Vec2<T>], dst: &[Vec2<T>]) -> Option<Self> {
+        if src.len() < 4 || src.len() != dst.len() {
+            return None;
+        }
+
+        let zero = identities::zero::<T>();
+        let one = identities::one::<T>();
+
+        let (src_norm, _) = conditioning(src)?;
+        let (dst_norm, dst_denorm) = conditioning(dst)?;
+
+        //accumulate A^T * A directly instead of building the 2N x 9 matrix A
+        let mut ata = [[zero; 9]; 9];
+        for (&p, &q) in src.iter().zip(dst) {
+            let p = src_norm.transform_point2(p);
+            let q = dst_norm.transform_point2(q);
+
+            let rows = [
+                [
+                    -p.x,
+                    -p.y,
+                    -one,
+                    zero,
+                    zero,
+                    zero,
+                    q.x * p.x,
+                    q.x * p.y,
+                    q.x,
+                ],
+                [
+                    zero,
+                    zero,
+                    zero,
+                    -p.x,
+                    -p.y,
+                    -one,
+                    q.y * p.x,
+                    q.y * p.y,
+                    q.y,
+                ],
+            ];
+
+            for row in rows.iter() {
+                for i in 0..9 {
+                    for j in 0..9 {
+                        ata[i][j] += row[i] * row[j];
+                    }
+                }
+            }
+        }
+
+        //the solution is the eigenvector of the smallest eigenvalue
+        let (values, vectors) = jacobi_eigen(ata);
+        let mut smallest = 0;
+        for i in 1..9 {
+            if values[i] < values[smallest] {
+                smallest = i;
+            }
+        }
+        let h = vectors[smallest];
+
+        //`h` holds the matrix row by row
+        let normalized_h = Mat3::new(h[0], h[3], h[6], h[1], h[4], h[7], h[2], h[5], h[8]);
+
+        Some(normalized(dst_denorm * normalized_h * src_norm))
+    }
+}
+
+//the matrix taking the canonical basis (1, 0, 0), (0, 1, 0), (0, 0, 1), (1, 1, 1) to the quad
+fn projective_basis<T: FloatScalar>(quad: [Vec2<T>; 4]) -> Option<Mat3<T>> {
+    let one = identities::one::<T>();
+    let basis = Mat3::from((
+        quad[0].extend(one),
+        quad[1].extend(one),
+        quad[2].extend(one),
+    ));
+
+    let lambda = basis.inverse()? * quad[3].extend(one);
+    let scale = lambda.x.abs().max(lambda.y.abs()).max(lambda.z.abs());
+
+    //the fourth point is collinear with two of the others
+    if lambda.x.abs().min(lambda.y.abs()).min(lambda.z.abs()) <= T::epsilon() * scale {
+        return None;
+    }
+
+    Some(Mat3::from((
+        Vec3::from(basis[0]) * lambda.x,
+        Vec3::from(basis[1]) * lambda.y,
+        Vec3::from(basis[2]) * lambda.z,
+    )))
+}
+
+//similarity moving the centroid of the points to the origin with a mean distance of sqrt(2),
+//returned with its inverse
+fn conditioning<T: FloatScalar>(points: &[Vec2<T>]) -> Option<(Mat3<T>, Mat3<T>)> {
+    let n = cast::cast::<usize, T>(points.len()).unwrap();
+    let centroid = points.iter().fold(Vec2::default(), |acc, &p| acc + p) / n;
+    let mean = points.iter().fold(identities::zero::<T>(), |acc, &p| {
+        acc + (p - centroid).magnitude()
+    }) / n;
+
+    if mean <= T::epsilon() {
+        return None;
+    }
+
+    let s = cast::cast::<f64, T>(core::f64::consts::SQRT_2).unwrap() / mean;
+    let inv = identities::one::<T>() / s;
+    let forward = Mat3::scale_2d(Vec2::new(s, s)) * Mat3::translation_2d(-centroid);
+    let backward = Mat3::translation_2d(centroid) * Mat3::scale_2d(Vec2::new(inv, inv));
+
+    Some((forward, backward))
+}
+
+//homographies are defined up to scale, fix the bottom right element to one when possible
+fn normalized<T: FloatScalar>(m: Mat3<T>) -> Mat3<T> {
+    let h33 = m[2][2];
+    if h33.abs() > T::epsilon() {
+        m * (identities::one::<T>() / h33)
+    } else {
+        m
+    }
+}
@@ -3,9 +3,9 @@ macro_rules! impl_mat_ops {
     //basic ops
     ($MatN:ident, $field:ident, $dimension:expr, $indextype:ty) => {
         // use crate::FloatScalar;
-        use std::ops::*;
+        use core::ops::*;
 
-        impl<T: FloatScalar> Add<$MatN<T>> for $MatN<T> {
+        impl<T: RealScalar> Add<$MatN<T>> for $MatN<T> {
             type Output = $MatN<T>;
 
             fn add(self, other: $MatN<T>) -> Self::Output {
@@ -20,7 +20,7 @@ macro_rules! impl_mat_ops {
             }
         }
 
-        impl<T: FloatScalar> AddAssign<$MatN<T>> for $MatN<T> {
+        impl<T: RealScalar> AddAssign<$MatN<T>> for $MatN<T> {
             fn add_assign(&mut self, other: $MatN<T>) {
                 for i in 0..$dimension {
                     for j in 0..$dimension {
@@ -30,7 +30,7 @@ macro_rules! impl_mat_ops {
             }
         }
 
-        impl<T: FloatScalar> Sub<$MatN<T>> for $MatN<T> {
+        impl<T: RealScalar> Sub<$MatN<T>> for $MatN<T> {
             type Output = Self;
 
             fn sub(self, other: $MatN<T>) -> Self {
@@ -45,7 +45,7 @@ macro_rules! impl_mat_ops {
             }
         }
 
-        impl<T: FloatScalar> SubAssign<$MatN<T>> for $MatN<T> {
+        impl<T: RealScalar> SubAssign<$MatN<T>> for $MatN<T> {
             fn sub_assign(&mut self, other: $MatN<T>) {
                 for i in 0..$dimension {
                     for j in 0..$dimension {
@@ -55,7 +55,7 @@ macro_rules! impl_mat_ops {
             }
         }
 
-        impl<T: FloatScalar> Mul<T> for $MatN<T> {
+        impl<T: RealScalar> Mul<T> for $MatN<T> {
             type Output = Self;
 
             fn mul(self, other: T) -> Self {
@@ -70,10 +70,15 @@ macro_rules! impl_mat_ops {
             }
         }
 
-        impl<T: FloatScalar> Mul<$MatN<T>> for $MatN<T> {
+        impl<T: RealScalar> Mul<$MatN<T>> for $MatN<T> {
             type Output = Self;
 
             fn mul(self, rhs: $MatN<T>) -> Self {
+                #[cfg(feature = "simd")]
+                if let Some(ret) = crate::simd::mat4_mul(&self, &rhs) {
+                    return ret;
+                }
+
                 let mut ret = $MatN::zero();
 
                 for i in 0..$dimension {
@@ -100,16 +105,44 @@ macro_rules! impl_mat_ops {
                 &mut self.$field[index]
             }
         }
+
+        impl<T> $MatN<T> {
+            ///Applies `f` to every element
+            #[inline]
+            pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> $MatN<U> {
+                $MatN {
+                    $field: self.$field.map(|column| column.map(&mut f)),
+                }
+            }
+        }
+
+        impl<T: Copy + num_traits::ToPrimitive> $MatN<T> {
+            ///Converts every element to `U`, returns `None` if any of them can't be represented
+            #[inline]
+            pub fn cast<U: num_traits::NumCast>(self) -> Option<$MatN<U>> {
+                let ret = self.map(U::from);
+                if ret.$field.iter().flatten().any(Option::is_none) {
+                    return None;
+                }
+
+                Some(ret.map(Option::unwrap))
+            }
+        }
     };
 
     //Matrix x Vector multiplication
     ($MatN:ident, $VecN:ident, $dimension:expr) => {
-        use std::ops::Mul;
+        use core::ops::Mul;
 
-        impl<T: FloatScalar> Mul<$VecN<T>> for $MatN<T> {
+        impl<T: RealScalar> Mul<$VecN<T>> for $MatN<T> {
             type Output = $VecN<T>;
 
             fn mul(self, other: $VecN<T>) -> $VecN<T> {
+                #[cfg(feature = "simd")]
+                if let Some(ret) = crate::simd::mat4_mul_vec(&self, other) {
+                    return ret;
+                }
+
                 let mut ret = $VecN::default();
 
                 for i in 0..$dimension {
@@ -3,8 +3,8 @@ use num_traits::identities;
 use crate::vectors::Vec2;
 use crate::{FloatScalar, RealScalar};
 
-use std::convert::From;
-use std::default::Default;
+use core::convert::From;
+use core::default::Default;
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -30,12 +30,58 @@ impl<T: RealScalar> Mat2<T> {
             mat: [[zero, zero], [zero, zero]],
         }
     }
+
+    #[inline]
+    pub fn transpost(&self) -> Self {
+        Mat2 {
+            mat: [[self[0][0], self[1][0]], [self[0][1], self[1][1]]],
+        }
+    }
+
+    #[inline]
+    pub fn transpose(&mut self) {
+        *self = Mat2 {
+            mat: [[self[0][0], self[1][0]], [self[0][1], self[1][1]]],
+        };
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self[0][0] * self[1][1] - self[1][0] * self[0][1]
+    }
+
+    pub fn inverse(&self) -> Option<Mat2<T>> {
+        let determinant = self.determinant();
+        if determinant == identities::zero::<T>() {
+            return None;
+        }
+
+        let inv = identities::one::<T>() / determinant;
+        Some(Mat2::new(
+            self[1][1] * inv,
+            -self[0][1] * inv,
+            -self[1][0] * inv,
+            self[0][0] * inv,
+        ))
+    }
+
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        &self[0][0] as *const T
+    }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        &mut self[0][0] as *mut T
+    }
 }
 
 impl<T: FloatScalar> Mat2<T> {
     //====================================== TRANSFORMATION MATRICES ===============================
 
-    ///Returns a rotation Matrix around the origin
+    ///Returns a clockwise rotation Matrix around the origin by `ang` radians. `Rotor2::new` and
+    ///`Mat3::rotation_2d` rotate counterclockwise instead, `Rotor2::new(ang).to_mat2()` being
+    ///`Mat2::rotation(-ang)`
     #[inline]
     pub fn rotation(ang: T) -> Self {
         Mat2 {
@@ -134,41 +180,12 @@ impl<T: FloatScalar> Mat2<T> {
     }
 
     //====================================================================================
-
-    #[inline]
-    pub fn transpost(&self) -> Self {
-        Mat2 {
-            mat: [[self[0][0], self[1][0]], [self[0][1], self[1][1]]],
-        }
-    }
-
-    #[inline]
-    pub fn transpose(&mut self) {
-        *self = Mat2 {
-            mat: [[self[0][0], self[1][0]], [self[0][1], self[1][1]]],
-        };
-    }
-
-    #[inline]
-    pub fn determinant(&self) -> T {
-        self[0][0] * self[1][1] - self[1][0] * self[0][1]
-    }
-
-    #[inline]
-    pub fn as_ptr(&self) -> *const T {
-        &self[0][0] as *const T
-    }
-
-    #[inline]
-    pub fn as_mut_ptr(&mut self) -> *mut T {
-        &mut self[0][0] as *mut T
-    }
 }
 
 impl_mat_ops!(Mat2, mat, 2, [T; 2]);
 impl_mat_ops!(Mat2, Vec2, 2);
 
-impl<T: FloatScalar> Default for Mat2<T> {
+impl<T: RealScalar> Default for Mat2<T> {
     fn default() -> Self {
         Mat2 {
             mat: [
@@ -1,11 +1,14 @@
 use num_traits::identities;
 
-use std::convert::From;
-use std::default::Default;
+use core::cmp::Ordering;
+use core::convert::From;
+use core::default::Default;
 
 use crate::euler::Euler;
+use crate::matrix::eigen::jacobi_eigen;
 use crate::matrix::Mat2;
 use crate::quaternions::Quaternion;
+use crate::vectors::Vec2;
 use crate::vectors::Vec3;
 use crate::{FloatScalar, RealScalar};
 
@@ -18,6 +21,7 @@ pub struct Mat3<T> {
 #[allow(dead_code)]
 impl<T> Mat3<T> {
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         s0e0: T,
         s0e1: T,
@@ -237,6 +241,33 @@ impl<T: FloatScalar> Mat3<T> {
         }
     }
 
+    ///Returns a translation Matrix by `t` for homogeneous 2D points
+    #[inline]
+    pub fn translation_2d(t: Vec2<T>) -> Self {
+        let zero = identities::zero::<T>();
+        let one: T = identities::one::<T>();
+        Mat3 {
+            mat: [[one, zero, zero], [zero, one, zero], [t.x, t.y, one]],
+        }
+    }
+
+    ///Returns a counterclockwise rotation Matrix around the origin by `ang` radians for homogeneous
+    ///2D points, matching `Rotor2::new(ang)`. `Mat2::rotation` turns clockwise instead
+    #[inline]
+    pub fn rotation_2d(ang: T) -> Self {
+        Mat3::rotation_z(ang)
+    }
+
+    ///Returns a scale Matrix by `k.x` and `k.y` along each axis for homogeneous 2D points
+    #[inline]
+    pub fn scale_2d(k: Vec2<T>) -> Self {
+        let zero = identities::zero::<T>();
+        let one: T = identities::one::<T>();
+        Mat3 {
+            mat: [[k.x, zero, zero], [zero, k.y, zero], [zero, zero, one]],
+        }
+    }
+
     //=============================================================================================================
 
     #[inline]
@@ -245,7 +276,7 @@ impl<T: FloatScalar> Mat3<T> {
         *self = Mat3 {
             mat: [
                 [temp.mat[0][0], temp.mat[1][0], temp.mat[2][0]],
-                [temp.mat[1][0], temp.mat[1][1], temp.mat[2][1]],
+                [temp.mat[0][1], temp.mat[1][1], temp.mat[2][1]],
                 [temp.mat[0][2], temp.mat[1][2], temp.mat[2][2]],
             ],
         }
@@ -256,7 +287,7 @@ impl<T: FloatScalar> Mat3<T> {
         Mat3 {
             mat: [
                 [self.mat[0][0], self.mat[1][0], self.mat[2][0]],
-                [self.mat[1][0], self.mat[1][1], self.mat[2][1]],
+                [self.mat[0][1], self.mat[1][1], self.mat[2][1]],
                 [self.mat[0][2], self.mat[1][2], self.mat[2][2]],
             ],
         }
@@ -291,41 +322,175 @@ impl<T: FloatScalar> Mat3<T> {
 
     pub fn cofactor(&self, i: usize, j: usize) -> T {
         let one = identities::one::<T>();
-        let sign = if (i + j) % 2 == 0 { one } else { -one };
+        let sign = if (i + j) % 2 == 1 { -one } else { one };
 
         sign * self.minor(i, j)
     }
 
+    ///The triple product of the columns
     #[inline]
     pub fn determinant(&self) -> T {
-        let zero = identities::zero::<T>();
-        let mut ret: T = zero;
-
-        //hardcoded column value since the result is the same in any other
-        for i in 0..3 {
-            ret += self[0][i] * self.cofactor(0, i);
-        }
-        ret
+        Vec3::from(self[0]).dot(Vec3::from(self[1]).cross(Vec3::from(self[2])))
     }
 
     pub fn inverse(&self) -> Option<Mat3<T>> {
-        let determinant = self.determinant();
+        self.inverse_transpose().map(|m| m.transpost())
+    }
+
+    ///The transpose of the inverse, which transforms the normals of a surface transformed by `self`
+    pub fn inverse_transpose(&self) -> Option<Mat3<T>> {
+        let (a, b, c) = (
+            Vec3::from(self[0]),
+            Vec3::from(self[1]),
+            Vec3::from(self[2]),
+        );
         let zero = identities::zero::<T>();
         let one = identities::one::<T>();
+
+        //the rows of the adjugate are the cross products of the columns
+        let (bc, ca, ab) = (b.cross(c), c.cross(a), a.cross(b));
+        let determinant = a.dot(bc);
         if determinant == zero {
             return None;
         }
 
-        let div = one / determinant;
-        let mut adj = Mat3::default();
+        Some(Mat3::from((bc, ca, ab)) * (one / determinant))
+    }
 
-        //create the adjoint matrix
-        for i in 0..3 {
-            for j in 0..3 {
-                adj[j][i] = self.cofactor(i, j);
+    ///The inverse of a matrix with orthonormal columns, such as a rotation, which is its transpose
+    #[inline]
+    pub fn inverse_orthonormal(&self) -> Mat3<T> {
+        self.transpost()
+    }
+
+    ///Transforms the 2D point `p` as `(p.x, p.y, 1)` and divides the result by its last component
+    #[inline]
+    pub fn transform_point2(&self, p: Vec2<T>) -> Vec2<T> {
+        let h = *self * p.extend(identities::one::<T>());
+        Vec2::new(h.x / h.z, h.y / h.z)
+    }
+
+    ///Eigen decomposition of a symmetric matrix, returned as `(values, vectors)`.
+    ///
+    ///The eigenvalues are sorted in decreasing order and the `i`-th column of `vectors` is the unit
+    ///eigenvector of the `i`-th eigenvalue. Only meaningful if `self` is symmetric.
+    pub fn symmetric_eigen(&self) -> (Vec3<T>, Mat3<T>) {
+        let (values, vectors) = jacobi_eigen(self.mat);
+
+        let mut order = [0, 1, 2];
+        //ties keep their order, like a stable sort would. NaNs compare equal to anything instead
+        //of panicking
+        order.sort_unstable_by(|&a, &b| {
+            values[b]
+                .partial_cmp(&values[a])
+                .unwrap_or(Ordering::Equal)
+                .then(a.cmp(&b))
+        });
+
+        (
+            Vec3::new(values[order[0]], values[order[1]], values[order[2]]),
+            Mat3 {
+                mat: [vectors[order[0]], vectors[order[1]], vectors[order[2]]],
+            },
+        )
+    }
+
+    ///Singular value decomposition `self = U * diag(S) * V^T`, returned as `(U, S, V)`.
+    ///
+    ///Uses one-sided Jacobi rotations. The singular values are sorted in decreasing order and
+    ///`U` and `V` are orthonormal, although either of them may contain a reflection.
+    pub fn svd(&self) -> (Mat3<T>, Vec3<T>, Mat3<T>) {
+        let zero = identities::zero::<T>();
+        let one = identities::one::<T>();
+        let two = one + one;
+
+        let mut u = [
+            Vec3::from(self[0]),
+            Vec3::from(self[1]),
+            Vec3::from(self[2]),
+        ];
+        let mut v = [
+            Vec3::new(one, zero, zero),
+            Vec3::new(zero, one, zero),
+            Vec3::new(zero, zero, one),
+        ];
+
+        //rotate pairs of columns until they are all orthogonal to each other
+        for _ in 0..32 {
+            let mut rotated = false;
+
+            for &(p, q) in [(0, 1), (0, 2), (1, 2)].iter() {
+                let alpha = u[p].squared_mag();
+                let beta = u[q].squared_mag();
+                let gamma = u[p].dot(u[q]);
+
+                if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() || gamma == zero {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (two * gamma);
+                let t = zeta.signum() / (zeta.abs() + (one + zeta * zeta).sqrt());
+                let c = one / (one + t * t).sqrt();
+                let s = c * t;
+
+                let (up, uq) = (u[p], u[q]);
+                u[p] = up * c - uq * s;
+                u[q] = up * s + uq * c;
+
+                let (vp, vq) = (v[p], v[q]);
+                v[p] = vp * c - vq * s;
+                v[q] = vp * s + vq * c;
             }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let mut order = [0, 1, 2];
+        let sigma = [u[0].magnitude(), u[1].magnitude(), u[2].magnitude()];
+        order.sort_unstable_by(|&a, &b| {
+            sigma[b]
+                .partial_cmp(&sigma[a])
+                .unwrap_or(Ordering::Equal)
+                .then(a.cmp(&b))
+        });
+
+        let s = Vec3::new(sigma[order[0]], sigma[order[1]], sigma[order[2]]);
+        let v = [v[order[0]], v[order[1]], v[order[2]]];
+        let mut u = [u[order[0]], u[order[1]], u[order[2]]];
+
+        //columns of U with a null singular value can't be normalized, rebuild them from the others
+        let tiny = T::epsilon() * s.x;
+        if s.x <= T::min_positive_value() {
+            u[0] = Vec3::new(one, zero, zero);
+        } else {
+            u[0] /= s.x;
+        }
+
+        if s.y <= tiny {
+            let axis = if u[0].x.abs() < u[0].y.abs() {
+                Vec3::new(one, zero, zero)
+            } else {
+                Vec3::new(zero, one, zero)
+            };
+            u[1] = u[0].cross(axis).normalized();
+        } else {
+            u[1] /= s.y;
         }
-        Some(adj * div)
+
+        if s.z <= tiny {
+            u[2] = u[0].cross(u[1]);
+        } else {
+            u[2] /= s.z;
+        }
+
+        (
+            Mat3::from((u[0], u[1], u[2])),
+            s,
+            Mat3::from((v[0], v[1], v[2])),
+        )
     }
 
     #[inline]
@@ -1,7 +1,7 @@
 use num_traits::identities;
 
-use std::convert::From;
-use std::default::Default;
+use core::convert::From;
+use core::default::Default;
 
 use crate::euler::Euler;
 use crate::matrix::Mat3;
@@ -10,6 +10,21 @@ use crate::vectors::Vec3;
 use crate::vectors::Vec4;
 use crate::{FloatScalar, RealScalar};
 
+//where the minor of each pair of rows sits in the arrays of `Mat4::pair_minors`, `R13` being the
+//minor over the rows 1 and 3
+pub(crate) mod pair {
+    pub(crate) const R01: usize = 0;
+    pub(crate) const R02: usize = 1;
+    pub(crate) const R03: usize = 2;
+    pub(crate) const R12: usize = 3;
+    pub(crate) const R13: usize = 4;
+    pub(crate) const R23: usize = 5;
+
+    pub(crate) const ROWS: [(usize, usize); 6] = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+}
+
+use pair::*;
+
 ///A column major 4x4 matrix
 #[repr(C)]
 #[derive(Debug, PartialEq, Copy, Clone)]
@@ -20,6 +35,7 @@ pub struct Mat4<T> {
 #[allow(dead_code)]
 impl<T> Mat4<T> {
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         s0e0: T,
         s0e1: T,
@@ -386,20 +402,39 @@ impl<T: FloatScalar> Mat4<T> {
     }
 
     pub fn cofactor(&self, i: usize, j: usize) -> T {
-        T::from::<i32>((-1 as i32).pow((i + j) as u32)).unwrap() * self.minor(i, j)
+        T::from::<i32>((-1_i32).pow((i + j) as u32)).unwrap() * self.minor(i, j)
     }
 
     pub fn determinant(&self) -> T {
-        let mut ret = identities::zero::<T>();
+        self.pair_minors().2
+    }
 
-        for i in 0..4 {
-            ret += self[i][0] * self.cofactor(i, 0);
+    //the 2x2 minors of the columns 0, 1 and of the columns 2, 3 for each pair of rows, indexed by
+    //the constants of `pair`, and the determinant built from them
+    pub(crate) fn pair_minors(&self) -> ([T; 6], [T; 6], T) {
+        let mut s = [identities::zero::<T>(); 6];
+        let mut c = [identities::zero::<T>(); 6];
+
+        for (k, &(i, j)) in ROWS.iter().enumerate() {
+            s[k] = self[0][i] * self[1][j] - self[1][i] * self[0][j];
+            c[k] = self[2][i] * self[3][j] - self[3][i] * self[2][j];
         }
-        ret
+
+        //Laplace expansion along the columns 0 and 1, each minor paired with the one over the
+        //other two rows
+        let determinant = s[R01] * c[R23] - s[R02] * c[R13] + s[R03] * c[R12] + s[R12] * c[R03]
+            - s[R13] * c[R02]
+            + s[R23] * c[R01];
+        (s, c, determinant)
     }
 
     pub fn inverse(&self) -> Option<Mat4<T>> {
-        let determinant = self.determinant();
+        #[cfg(feature = "simd")]
+        if let Some(ret) = crate::simd::mat4_inverse(self) {
+            return ret;
+        }
+
+        let (s, c, determinant) = self.pair_minors();
         let zero = identities::zero::<T>();
         let one = identities::one::<T>();
 
@@ -407,16 +442,76 @@ impl<T: FloatScalar> Mat4<T> {
             return None;
         }
 
-        let div = one / determinant;
-        let mut temp = Self::default();
+        //column `i` of the inverse holds the cofactors of row `i`. Each one is expanded along the
+        //other pair of columns: an entry of row `k` times the minor over the two rows left after
+        //removing `i` and `k`. The entries come from the columns 1, 0, 3, 2, the first two
+        //multiplying the minors of the columns 2, 3 and the last two those of the columns 0, 1
+        let inv = one / determinant;
+        let e = |k: usize| [self[1][k], self[0][k], self[3][k], self[2][k]];
+        let f = |n: usize| [c[n], c[n], s[n], s[n]];
+        let column =
+            |(a, fa): (usize, usize), (b, fb): (usize, usize), (d, fd): (usize, usize), sign: T| {
+                let (ea, eb, ed) = (e(a), e(b), e(d));
+                let (fa, fb, fd) = (f(fa), f(fb), f(fd));
+                let signs = [sign, -sign, sign, -sign];
+                let mut ret = [zero; 4];
+
+                for l in 0..4 {
+                    ret[l] = (ea[l] * fa[l] - eb[l] * fb[l] + ed[l] * fd[l]) * signs[l];
+                }
+                ret
+            };
+
+        //each row paired with the minor over the remaining two
+        Some(Mat4::from([
+            column((1, R23), (2, R13), (3, R12), inv),
+            column((0, R23), (2, R03), (3, R02), -inv),
+            column((0, R13), (1, R03), (3, R01), inv),
+            column((0, R12), (1, R02), (2, R01), -inv),
+        ]))
+    }
 
-        for i in 0..4 {
-            for j in 0..4 {
-                temp[j][i] = self.cofactor(i, j);
-            }
-        }
+    ///The transpose of the inverse, which transforms the normals of a surface transformed by `self`
+    #[inline]
+    pub fn inverse_transpose(&self) -> Option<Mat4<T>> {
+        self.inverse().map(|m| m.transpost())
+    }
+
+    ///The inverse of a rigid transform, a rotation followed by a translation. The rotation is
+    ///transposed instead of inverted
+    pub fn inverse_orthonormal(&self) -> Mat4<T> {
+        let rotation = self.linear_part().inverse_orthonormal();
+
+        Self::from_linear_translation(rotation, -(rotation * Vec4::from(self[3]).truncate()))
+    }
+
+    ///The inverse of an affine transform, whose last row is `(0, 0, 0, 1)`, or `None` if its
+    ///linear part is singular
+    pub fn inverse_affine(&self) -> Option<Mat4<T>> {
+        let linear = self.linear_part().inverse()?;
+
+        Some(Self::from_linear_translation(
+            linear,
+            -(linear * Vec4::from(self[3]).truncate()),
+        ))
+    }
+
+    //the upper left 3x3 block
+    #[inline]
+    fn linear_part(&self) -> Mat3<T> {
+        Mat3::from([0, 1, 2].map(|i| [self[i][0], self[i][1], self[i][2]]))
+    }
+
+    #[inline]
+    fn from_linear_translation(linear: Mat3<T>, translation: Vec3<T>) -> Mat4<T> {
+        let zero = identities::zero::<T>();
 
-        Some(temp * div)
+        Mat4::from((
+            Vec3::from(linear[0]).extend(zero),
+            Vec3::from(linear[1]).extend(zero),
+            Vec3::from(linear[2]).extend(zero),
+            translation.extend(identities::one::<T>()),
+        ))
     }
 
     #[inline]
@@ -1,9 +1,15 @@
 #[macro_use]
 mod macros;
+mod eigen;
+mod exponential;
+mod homography;
 mod mat2;
 mod mat3;
 mod mat4;
+mod orthonormal;
 
 pub use mat2::Mat2;
 pub use mat3::Mat3;
+pub(crate) use mat4::pair;
 pub use mat4::Mat4;
+pub use orthonormal::orthonormalize;
@@ -0,0 +1,104 @@
+use num_traits::identities;
+
+use crate::matrix::Mat3;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+impl<T: FloatScalar> Mat3<T> {
+    ///Re-orthonormalizes the columns with Gram-Schmidt: the first column keeps its direction, the
+    ///second one stays in the plane of the first two and the third one is only corrected. Cheap
+    ///enough to run after every incremental update of a rotation.
+    ///
+    ///Zero or dependent columns are replaced by the cross product of the other ones, so the result
+    ///is always a rotation or, if the input was a reflection, a reflection.
+    pub fn orthonormalized(&self) -> Self {
+        let mut basis = [
+            Vec3::from(self[0]),
+            Vec3::from(self[1]),
+            Vec3::from(self[2]),
+        ];
+        let rank = orthonormalize(&mut basis);
+
+        //dependent columns were zeroed, rebuild them from the surviving ones in cyclic order so
+        //the completed basis is right handed
+        let zero = Vec3::default();
+        let kept = [basis[0] != zero, basis[1] != zero, basis[2] != zero];
+        match rank {
+            0 => return Mat3::default(),
+            1 => {
+                let i = kept.iter().position(|&k| k).unwrap();
+                let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+                basis[j] = basis[i].any_orthogonal();
+                basis[k] = basis[i].cross(basis[j]);
+            }
+            2 => {
+                let k = kept.iter().position(|&k| !k).unwrap();
+                basis[k] = basis[(k + 1) % 3].cross(basis[(k + 2) % 3]);
+            }
+            _ => {}
+        }
+
+        Mat3::from((basis[0], basis[1], basis[2]))
+    }
+
+    ///The rotation closest to `self` in the Frobenius norm, from its polar decomposition. Unlike
+    ///`orthonormalized` it spreads the correction evenly over every axis, and it never returns a
+    ///reflection.
+    pub fn nearest_rotation(&self) -> Self {
+        let zero = identities::zero::<T>();
+        let one = identities::one::<T>();
+        let (u, _, v) = self.svd();
+
+        //flip the least significant axis if U * V^T would be a reflection
+        let d = if u.determinant() * v.determinant() < zero {
+            -one
+        } else {
+            one
+        };
+
+        u * Mat3::from([[one, zero, zero], [zero, one, zero], [zero, zero, d]]) * v.transpost()
+    }
+
+    ///Checks that the columns are unit length and perpendicular to each other, up to `tol`
+    pub fn is_orthonormal(&self, tol: T) -> bool {
+        let one = identities::one::<T>();
+        let gram = self.transpost() * *self;
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { one } else { identities::zero::<T>() };
+                if (gram[i][j] - expected).abs() > tol {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+///Turns `basis` into an orthonormal set in place with modified Gram-Schmidt, in order.
+///
+///Vectors that are (almost) linearly dependent on the previous ones are set to zero. Returns the
+///number of independent vectors found.
+pub fn orthonormalize<T: FloatScalar>(basis: &mut [Vec3<T>]) -> usize {
+    let tol = T::epsilon().sqrt();
+    let mut rank = 0;
+
+    for i in 0..basis.len() {
+        let mut v = basis[i];
+        let len = v.magnitude();
+
+        for &u in &basis[..i] {
+            v -= u * v.dot(u);
+        }
+
+        let residual = v.magnitude();
+        if residual > tol * len {
+            basis[i] = v / residual;
+            rank += 1;
+        } else {
+            basis[i] = Vec3::default();
+        }
+    }
+    rank
+}
@@ -1,27 +1,55 @@
-use std::fmt;
+use core::fmt;
 
 use crate::vectors::Vec2;
+use core::ops;
 use num_traits::{Float, Num, NumAssign, NumAssignOps, NumCast};
-use std::ops;
-
-pub trait RealScalar:
-    Copy
-    + Clone
-    + Num
-    + NumAssign
-    + NumCast
-    + NumAssignOps
-    + Default
-    + fmt::Debug
-    + ops::Neg<Output = Self>
+
+///Any number supporting the basic arithmetic, including the unsigned integers
+pub trait Scalar:
+    Copy + Clone + Num + NumAssign + NumCast + NumAssignOps + Default + fmt::Debug + SimdScalar
 {
 }
 
-impl<T> RealScalar for T where
-    T: Copy + NumCast + Clone + fmt::Debug + Num + NumAssign + Default + ops::Neg<Output = Self>
+impl<T> Scalar for T where
+    T: Copy + NumCast + Clone + fmt::Debug + Num + NumAssign + Default + SimdScalar
 {
 }
 
+///What the `simd` feature asks of every `Scalar`. With it the scalars must be `'static`, so the
+///generic code can tell when it runs on `f32` and use the SIMD kernels. Without it every type is a
+///`SimdScalar`
+#[cfg(feature = "simd")]
+pub trait SimdScalar: 'static {}
+
+#[cfg(feature = "simd")]
+impl<T: 'static> SimdScalar for T {}
+
+#[cfg(not(feature = "simd"))]
+pub trait SimdScalar {}
+
+#[cfg(not(feature = "simd"))]
+impl<T> SimdScalar for T {}
+
+///What the `rayon` feature asks of the scalars of the slice transforms, such as
+///`Mat4::transform_points`. With it they must be `Send + Sync` to be shared between the threads.
+///Without it every type is a `BatchScalar`
+#[cfg(feature = "rayon")]
+pub trait BatchScalar: Send + Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T: Send + Sync> BatchScalar for T {}
+
+#[cfg(not(feature = "rayon"))]
+pub trait BatchScalar {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T> BatchScalar for T {}
+
+///A `Scalar` that can be negated
+pub trait RealScalar: Scalar + ops::Neg<Output = Self> {}
+
+impl<T> RealScalar for T where T: Scalar + ops::Neg<Output = Self> {}
+
 pub trait FloatScalar: RealScalar + Float {}
 
 impl<T> FloatScalar for T where T: RealScalar + Float {}
@@ -0,0 +1,78 @@
+use crate::euler::Euler;
+use crate::matrix::{Mat2, Mat3, Mat4};
+use crate::quaternions::Quaternion;
+use crate::vectors::{Vec2, Vec3, Vec4};
+
+//conversions between scalar types that can't lose precision
+macro_rules! impl_widening {
+    ($($Narrow:ty => $Wide:ty),+) => {
+        $(
+            impl_widening!(@vec $Narrow, $Wide; Vec2, Vec3, Vec4);
+            impl_widening!(@mat $Narrow, $Wide; Mat2, Mat3, Mat4);
+        )+
+    };
+    (@vec $Narrow:ty, $Wide:ty; $($VecN:ident),+) => {
+        $(
+            impl From<$VecN<$Narrow>> for $VecN<$Wide> {
+                #[inline]
+                fn from(v: $VecN<$Narrow>) -> Self {
+                    v.map(<$Wide>::from)
+                }
+            }
+        )+
+    };
+    (@mat $Narrow:ty, $Wide:ty; $($MatN:ident),+) => {
+        $(
+            impl From<$MatN<$Narrow>> for $MatN<$Wide> {
+                #[inline]
+                fn from(m: $MatN<$Narrow>) -> Self {
+                    m.map(<$Wide>::from)
+                }
+            }
+        )+
+    };
+}
+
+impl_widening!(f32 => f64, i16 => f32, u16 => f32, i32 => f64, u32 => f64);
+
+impl From<Quaternion<f32>> for Quaternion<f64> {
+    #[inline]
+    fn from(q: Quaternion<f32>) -> Self {
+        Quaternion::new_sv(q.w.into(), q.v.into())
+    }
+}
+
+impl From<Euler<f32>> for Euler<f64> {
+    #[inline]
+    fn from(e: Euler<f32>) -> Self {
+        Euler::new(e.yaw.into(), e.pitch.into(), e.row.into())
+    }
+}
+
+impl Vec3<f64> {
+    ///The position relative to `origin`, usually the camera, narrowed to `f32`.
+    ///
+    ///The subtraction happens in `f64`, so positions far from the world origin keep their precision
+    ///as long as they are close to `origin`
+    #[inline]
+    pub fn camera_relative(self, origin: Vec3<f64>) -> Vec3<f32> {
+        (self - origin).map(|x| x as f32)
+    }
+}
+
+impl Mat4<f64> {
+    ///The transform followed by a translation by `-origin`, usually the camera position, narrowed
+    ///to `f32`. See `Vec3::camera_relative`
+    #[inline]
+    pub fn camera_relative(&self, origin: Vec3<f64>) -> Mat4<f32> {
+        let mut m = *self;
+        for i in 0..4 {
+            let w = m[i][3];
+            for j in 0..3 {
+                m[i][j] -= origin[j] * w;
+            }
+        }
+
+        m.map(|x| x as f32)
+    }
+}
@@ -1,8 +1,8 @@
 use num_traits::cast;
 use num_traits::identities;
 
-use std::convert::From;
-use std::ops::{Mul, Neg};
+use core::convert::From;
+use core::ops::{Mul, Neg};
 
 use super::Euler;
 use super::FloatScalar;
@@ -39,6 +39,15 @@ impl<T: FloatScalar> Quaternion<T> {
         Quaternion { w, v }
     }
 
+    ///Converts the components to `U`, returns `None` if any of them can't be represented
+    #[inline]
+    pub fn cast<U: FloatScalar>(self) -> Option<Quaternion<U>> {
+        Some(Quaternion {
+            w: U::from(self.w)?,
+            v: self.v.cast()?,
+        })
+    }
+
     #[inline]
     pub fn magnitude(self) -> T {
         (self.w.powi(2) + self.v.magnitude().powi(2)).sqrt()
@@ -63,6 +72,15 @@ impl<T: FloatScalar> Quaternion<T> {
         self.w * other.w + self.v.dot(other.v)
     }
 
+    ///Rotates `v` by the unit Quaternion `self`
+    #[inline]
+    pub fn rotate(self, v: Vec3<T>) -> Vec3<T> {
+        let two = identities::one::<T>() + identities::one::<T>();
+        let t = self.v.cross(v) * two;
+
+        v + t * self.w + self.v.cross(t)
+    }
+
     ///Raises self to the power of `exp`
     pub fn pow(self, exp: T) -> Self {
         if self.w.abs() > cast::cast::<f64, T>(0.99999).unwrap() {
@@ -123,9 +141,14 @@ impl<T: FloatScalar> Mul<Quaternion<T>> for Quaternion<T> {
     type Output = Self;
 
     fn mul(self, other: Quaternion<T>) -> Self::Output {
+        #[cfg(feature = "simd")]
+        if let Some(ret) = crate::simd::quaternion_mul(self, other) {
+            return ret;
+        }
+
         Quaternion {
             w: self.w * other.w - self.v.dot(other.v),
-            v: other.v * self.w + self.v + self.v.cross(other.v) * other.w,
+            v: other.v * self.w + self.v * other.w + self.v.cross(other.v),
         }
     }
 }
@@ -158,7 +181,7 @@ impl<T: FloatScalar> From<Mat3<T>> for Quaternion<T> {
         let mut ret = Quaternion::default();
 
         let W = mat[0][0] + mat[1][1] + mat[2][2];
-        let X = mat[0][0] - mat[1][1] + mat[2][2];
+        let X = mat[0][0] - mat[1][1] - mat[2][2];
         let Y = mat[1][1] - mat[0][0] - mat[2][2];
         let Z = mat[2][2] - mat[0][0] - mat[1][1];
 
@@ -184,103 +207,52 @@ impl<T: FloatScalar> From<Mat3<T>> for Quaternion<T> {
             (biggest + identities::one::<T>()).sqrt() * cast::cast::<f64, T>(0.5).unwrap();
         let mult = cast::cast::<f64, T>(0.25).unwrap() / largest;
 
+        //the matrix is column major, `mat[j][i]` is the element of row `i` and column `j`
         match index {
             0 => {
                 ret.w = largest;
-                ret.v.x = (mat[2][1] - mat[1][2]) * mult;
-                ret.v.y = (mat[0][2] - mat[2][0]) * mult;
-                ret.v.z = (mat[1][0] - mat[0][1]) * mult;
+                ret.v.x = (mat[1][2] - mat[2][1]) * mult;
+                ret.v.y = (mat[2][0] - mat[0][2]) * mult;
+                ret.v.z = (mat[0][1] - mat[1][0]) * mult;
                 ret
             }
 
             1 => {
                 ret.v.x = largest;
-                ret.w = (mat[2][1] - mat[1][2]) * mult;
+                ret.w = (mat[1][2] - mat[2][1]) * mult;
                 ret.v.y = (mat[1][0] + mat[0][1]) * mult;
-                ret.v.z = (mat[0][2] + mat[2][0]) * mult;
+                ret.v.z = (mat[2][0] + mat[0][2]) * mult;
                 ret
             }
 
             2 => {
                 ret.v.y = largest;
-                ret.w = (mat[0][2] - mat[2][0]) * mult;
+                ret.w = (mat[2][0] - mat[0][2]) * mult;
                 ret.v.x = (mat[1][0] + mat[0][1]) * mult;
                 ret.v.z = (mat[2][1] + mat[1][2]) * mult;
                 ret
             }
-            _ => ret,
-        }
-    }
-}
-
-#[allow(non_snake_case)]
-impl<T: FloatScalar> From<Mat4<T>> for Quaternion<T> {
-    fn from(mat: Mat4<T>) -> Self {
-        let mut ret = Quaternion::default();
-
-        let W = mat[0][0] + mat[1][1] + mat[2][2];
-        let X = mat[0][0] - mat[1][1] + mat[2][2];
-        let Y = mat[1][1] - mat[0][0] - mat[2][2];
-        let Z = mat[2][2] - mat[0][0] - mat[1][1];
 
-        let mut index = 0;
-        let mut biggest = W;
-
-        if X > biggest {
-            biggest = X;
-            index = 1;
-        }
-
-        if Y > biggest {
-            biggest = Y;
-            index = 2;
-        }
-
-        if Z > biggest {
-            biggest = Z;
-            index = 3;
-        }
-
-        let largest =
-            (biggest + identities::one::<T>()).sqrt() * cast::cast::<f64, T>(0.5).unwrap();
-        let mult = cast::cast::<f64, T>(0.25).unwrap() / largest;
-
-        match index {
-            0 => {
-                ret.w = largest;
-                ret.v.x = (mat[2][1] - mat[1][2]) * mult;
-                ret.v.y = (mat[0][2] - mat[2][0]) * mult;
-                ret.v.z = (mat[1][0] - mat[0][1]) * mult;
-                ret
-            }
-
-            1 => {
-                ret.v.x = largest;
-                ret.w = (mat[2][1] - mat[1][2]) * mult;
-                ret.v.y = (mat[1][0] + mat[0][1]) * mult;
-                ret.v.z = (mat[0][2] + mat[2][0]) * mult;
-                ret
-            }
-
-            2 => {
-                ret.v.y = largest;
-                ret.w = (mat[0][2] - mat[2][0]) * mult;
-                ret.v.x = (mat[1][0] + mat[0][1]) * mult;
-                ret.v.z = (mat[2][1] + mat[1][2]) * mult;
-                ret
-            }
-            3 => {
+            _ => {
                 ret.v.z = largest;
-                ret.w = (mat[1][0] - mat[0][1]) * mult;
-                ret.v.x = (mat[0][2] + mat[2][0]) * mult;
+                ret.w = (mat[0][1] - mat[1][0]) * mult;
+                ret.v.x = (mat[2][0] + mat[0][2]) * mult;
                 ret.v.y = (mat[2][1] + mat[1][2]) * mult;
                 ret
             }
-            _ => ret,
         }
     }
 }
 
+impl<T: FloatScalar> From<Mat4<T>> for Quaternion<T> {
+    fn from(mat: Mat4<T>) -> Self {
+        Quaternion::from(Mat3::new(
+            mat[0][0], mat[0][1], mat[0][2], mat[1][0], mat[1][1], mat[1][2], mat[2][0], mat[2][1],
+            mat[2][2],
+        ))
+    }
+}
+
 impl<T: FloatScalar> From<Euler<T>> for Quaternion<T> {
     fn from(euler: Euler<T>) -> Self {
         let two = identities::one::<T>() + identities::one::<T>();
@@ -0,0 +1,111 @@
+use num_traits::identities;
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::vectors::Vec3;
+use crate::{FloatScalar, RealScalar, Scalar};
+
+///A 3D bivector: an oriented plane with a magnitude, written on the basis `e12`, `e23`, `e31`.
+///
+///`a.wedge(b)` is the plane spanned by `a` and `b`, oriented from `a` towards `b`. Its components
+///match the ones of `a.cross(b)`: `xy` pairs with `z`, `yz` with `x` and `zx` with `y`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct Bivector3<T> {
+    pub xy: T,
+    pub yz: T,
+    pub zx: T,
+}
+
+#[allow(dead_code)]
+impl<T> Bivector3<T> {
+    #[inline]
+    pub const fn new(xy: T, yz: T, zx: T) -> Self {
+        Bivector3 { xy, yz, zx }
+    }
+}
+
+impl<T: Scalar> Bivector3<T> {
+    ///The plane orthogonal to `n`, oriented counterclockwise when looking down `n`
+    #[inline]
+    pub fn from_normal(n: Vec3<T>) -> Self {
+        Bivector3::new(n.z, n.x, n.y)
+    }
+
+    ///The normal of the plane, the Hodge dual of the bivector
+    #[inline]
+    pub fn normal(self) -> Vec3<T> {
+        Vec3::new(self.yz, self.zx, self.xy)
+    }
+
+    #[inline]
+    pub fn dot(self, other: Bivector3<T>) -> T {
+        self.xy * other.xy + self.yz * other.yz + self.zx * other.zx
+    }
+
+    #[inline]
+    pub fn squared_mag(self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: FloatScalar> Bivector3<T> {
+    ///The area of the oriented plane
+    #[inline]
+    pub fn magnitude(self) -> T {
+        self.squared_mag().sqrt()
+    }
+
+    #[inline]
+    pub fn normalized(self) -> Self {
+        self * (identities::one::<T>() / self.magnitude())
+    }
+}
+
+impl<T: FloatScalar> Vec3<T> {
+    ///Outer product, the plane spanned by `self` and `other` with the area of their parallelogram
+    #[inline]
+    pub fn wedge(self, other: Vec3<T>) -> Bivector3<T> {
+        Bivector3::new(
+            self.x * other.y - self.y * other.x,
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+        )
+    }
+}
+
+impl<T: Scalar> Add<Bivector3<T>> for Bivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Bivector3<T>) -> Self {
+        Bivector3::new(self.xy + other.xy, self.yz + other.yz, self.zx + other.zx)
+    }
+}
+
+impl<T: Scalar> Sub<Bivector3<T>> for Bivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Bivector3<T>) -> Self {
+        Bivector3::new(self.xy - other.xy, self.yz - other.yz, self.zx - other.zx)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Bivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, k: T) -> Self {
+        Bivector3::new(self.xy * k, self.yz * k, self.zx * k)
+    }
+}
+
+impl<T: RealScalar> Neg for Bivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Bivector3::new(-self.xy, -self.yz, -self.zx)
+    }
+}
@@ -0,0 +1,7 @@
+mod bivector3;
+mod rotor2;
+mod rotor3;
+
+pub use bivector3::Bivector3;
+pub use rotor2::Rotor2;
+pub use rotor3::Rotor3;
@@ -0,0 +1,142 @@
+use num_traits::identities;
+
+use core::ops::Mul;
+
+use crate::complex::Complex;
+use crate::matrix::{Mat2, Mat3};
+use crate::vectors::Vec2;
+use crate::FloatScalar;
+
+///A 2D rotation stored as a unit complex number
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rotor2<T> {
+    complex: Complex<T>,
+}
+
+impl<T: FloatScalar> Rotor2<T> {
+    #[inline]
+    pub fn identity() -> Self {
+        Rotor2 {
+            complex: Complex::new(identities::one::<T>(), identities::zero::<T>()),
+        }
+    }
+
+    ///Counterclockwise rotation by `ang` radians, like `Mat3::rotation_2d(ang)`. `Mat2::rotation`
+    ///turns clockwise, so the matching `Mat2` is `Mat2::rotation(-ang)`
+    #[inline]
+    pub fn new(ang: T) -> Self {
+        Rotor2 {
+            complex: Complex::cis(ang),
+        }
+    }
+
+    ///Wraps the rotation by the angle of `complex`, normalizing it
+    #[inline]
+    pub fn from_complex(complex: Complex<T>) -> Self {
+        Rotor2 {
+            complex: complex / complex.norm(),
+        }
+    }
+
+    ///The rotation taking the direction of `from` to the direction of `to`
+    #[inline]
+    pub fn between(from: Vec2<T>, to: Vec2<T>) -> Self {
+        //to * conj(from) has the angle between them
+        Rotor2::from_complex(Complex::new(
+            from.x * to.x + from.y * to.y,
+            from.x * to.y - from.y * to.x,
+        ))
+    }
+
+    ///Rotation angle in radians, in the range `(-pi, pi]`
+    #[inline]
+    pub fn angle(&self) -> T {
+        self.complex.arg()
+    }
+
+    #[inline]
+    pub fn complex(&self) -> Complex<T> {
+        self.complex
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Rotor2 {
+            complex: self.complex.conj(),
+        }
+    }
+
+    #[inline]
+    pub fn rotate(&self, v: Vec2<T>) -> Vec2<T> {
+        let c = self.complex;
+        Vec2::new(c.re * v.x - c.im * v.y, c.im * v.x + c.re * v.y)
+    }
+
+    ///Interpolates from `self` to `other` by the amount of `t` at constant angular speed, along
+    ///the shortest arc
+    #[inline]
+    pub fn slerp(&self, other: Rotor2<T>, t: T) -> Self {
+        let delta = (other * self.inverse()).angle();
+        Rotor2::new(delta * t) * *self
+    }
+
+    ///The counterclockwise rotation as a `Mat2`, equal to `Mat2::rotation(-angle)`
+    #[inline]
+    pub fn to_mat2(&self) -> Mat2<T> {
+        let c = self.complex;
+        Mat2::new(c.re, c.im, -c.im, c.re)
+    }
+
+    ///The rotation as a 2D homogeneous transform
+    #[inline]
+    pub fn to_mat3(&self) -> Mat3<T> {
+        let zero = identities::zero::<T>();
+        let c = self.complex;
+        Mat3::new(
+            c.re,
+            c.im,
+            zero,
+            -c.im,
+            c.re,
+            zero,
+            zero,
+            zero,
+            identities::one::<T>(),
+        )
+    }
+}
+
+impl<T: FloatScalar> Mul<Rotor2<T>> for Rotor2<T> {
+    type Output = Self;
+
+    ///Composes the rotations, applying `other` first
+    #[inline]
+    fn mul(self, other: Rotor2<T>) -> Self {
+        Rotor2 {
+            complex: self.complex * other.complex,
+        }
+    }
+}
+
+impl<T: FloatScalar> Mul<Vec2<T>> for Rotor2<T> {
+    type Output = Vec2<T>;
+
+    #[inline]
+    fn mul(self, v: Vec2<T>) -> Vec2<T> {
+        self.rotate(v)
+    }
+}
+
+impl<T: FloatScalar> From<Rotor2<T>> for Mat2<T> {
+    #[inline]
+    fn from(rotor: Rotor2<T>) -> Self {
+        rotor.to_mat2()
+    }
+}
+
+impl<T: FloatScalar> From<Rotor2<T>> for Mat3<T> {
+    #[inline]
+    fn from(rotor: Rotor2<T>) -> Self {
+        rotor.to_mat3()
+    }
+}
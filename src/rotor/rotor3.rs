@@ -0,0 +1,171 @@
+use num_traits::{cast, identities};
+
+use core::ops::Mul;
+
+use crate::matrix::Mat3;
+use crate::quaternions::Quaternion;
+use crate::rotor::Bivector3;
+use crate::vectors::Vec3;
+use crate::FloatScalar;
+
+///A 3D rotation written as a scalar plus a bivector, rotating vectors with `v' = R * v * ~R`.
+///
+///The rotor of `angle` radians in the unit plane `B` is `cos(angle / 2) - B * sin(angle / 2)`,
+///which turns the first vector of `B` towards the second one. Rotors map one to one to unit
+///Quaternions, the bivector being minus the vector part.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Rotor3<T> {
+    pub s: T,
+    pub b: Bivector3<T>,
+}
+
+#[allow(dead_code)]
+impl<T> Rotor3<T> {
+    #[inline]
+    pub const fn new(s: T, b: Bivector3<T>) -> Self {
+        Rotor3 { s, b }
+    }
+}
+
+impl<T: FloatScalar> Rotor3<T> {
+    #[inline]
+    pub fn identity() -> Self {
+        Rotor3::new(identities::one::<T>(), Bivector3::default())
+    }
+
+    ///Rotation of `angle` radians in `plane`, which doesn't need to be normalized
+    #[inline]
+    pub fn from_angle_plane(angle: T, plane: Bivector3<T>) -> Self {
+        let half = angle / (identities::one::<T>() + identities::one::<T>());
+        Rotor3::new(half.cos(), plane.normalized() * -half.sin())
+    }
+
+    ///The smallest rotation taking the direction of `from` to the direction of `to`
+    pub fn from_rotation_between(from: Vec3<T>, to: Vec3<T>) -> Self {
+        let from = from.normalized();
+        let to = to.normalized();
+        let s = identities::one::<T>() + from.dot(to);
+
+        //opposite directions, any plane containing them works
+        if s <= T::epsilon() {
+            return Rotor3::new(
+                identities::zero::<T>(),
+                -from.wedge(from.any_orthogonal()).normalized(),
+            );
+        }
+
+        //(1 + to * from) normalized, where to * from = to . from + to ^ from
+        Rotor3::new(s, to.wedge(from)).normalized()
+    }
+
+    #[inline]
+    pub fn magnitude(self) -> T {
+        (self.s * self.s + self.b.squared_mag()).sqrt()
+    }
+
+    #[inline]
+    pub fn normalized(self) -> Self {
+        let k = identities::one::<T>() / self.magnitude();
+        Rotor3::new(self.s * k, self.b * k)
+    }
+
+    ///Flips the sign of the bivector, which undoes the rotation of a unit rotor
+    #[inline]
+    pub fn reverse(self) -> Self {
+        Rotor3::new(self.s, -self.b)
+    }
+
+    #[inline]
+    pub fn dot(self, other: Rotor3<T>) -> T {
+        self.s * other.s + self.b.dot(other.b)
+    }
+
+    ///Rotates `v` by the unit rotor `self`
+    #[inline]
+    pub fn rotate(self, v: Vec3<T>) -> Vec3<T> {
+        Quaternion::from(self).rotate(v)
+    }
+
+    ///Interpolates from `self` to `other` by the amount of `t` at constant angular speed, along
+    ///the shortest arc
+    pub fn slerp(self, other: Rotor3<T>, t: T) -> Self {
+        let one = identities::one::<T>();
+        let mut cos = self.dot(other);
+        let mut other = other;
+
+        //R and -R are the same rotation
+        if cos < identities::zero::<T>() {
+            cos = -cos;
+            other = Rotor3::new(-other.s, -other.b);
+        }
+
+        let (k0, k1) = if cos > one - cast::cast::<f64, T>(1e-6).unwrap() {
+            (one - t, t)
+        } else {
+            let omega = cos.acos();
+            let sin = omega.sin();
+            (((one - t) * omega).sin() / sin, (t * omega).sin() / sin)
+        };
+
+        Rotor3::new(self.s * k0 + other.s * k1, self.b * k0 + other.b * k1).normalized()
+    }
+
+    #[inline]
+    pub fn to_mat3(self) -> Mat3<T> {
+        Mat3::from(Quaternion::from(self))
+    }
+}
+
+impl<T: FloatScalar> Mul<Rotor3<T>> for Rotor3<T> {
+    type Output = Self;
+
+    ///Composes the rotations, applying `other` first
+    #[inline]
+    fn mul(self, other: Rotor3<T>) -> Self {
+        //the geometric product of two even multivectors, the product of two bivectors gives
+        //a scalar and the negated commutator
+        let (a, b) = (self.b.normal(), other.b.normal());
+        Rotor3::new(
+            self.s * other.s - a.dot(b),
+            Bivector3::from_normal(b * self.s + a * other.s - a.cross(b)),
+        )
+    }
+}
+
+impl<T: FloatScalar> Mul<Vec3<T>> for Rotor3<T> {
+    type Output = Vec3<T>;
+
+    #[inline]
+    fn mul(self, v: Vec3<T>) -> Vec3<T> {
+        self.rotate(v)
+    }
+}
+
+impl<T: FloatScalar> From<Quaternion<T>> for Rotor3<T> {
+    #[inline]
+    fn from(quat: Quaternion<T>) -> Self {
+        Rotor3::new(quat.w, Bivector3::from_normal(-quat.v))
+    }
+}
+
+impl<T: FloatScalar> From<Rotor3<T>> for Quaternion<T> {
+    #[inline]
+    fn from(rotor: Rotor3<T>) -> Self {
+        Quaternion::new_sv(rotor.s, -rotor.b.normal())
+    }
+}
+
+impl<T: FloatScalar> From<Mat3<T>> for Rotor3<T> {
+    #[inline]
+    fn from(mat: Mat3<T>) -> Self {
+        Rotor3::from(Quaternion::from(mat))
+    }
+}
+
+impl<T: FloatScalar> From<Rotor3<T>> for Mat3<T> {
+    #[inline]
+    fn from(rotor: Rotor3<T>) -> Self {
+        rotor.to_mat3()
+    }
+}
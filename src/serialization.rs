@@ -0,0 +1,408 @@
+//! Serde support, enabled by the `serde` feature.
+//!
+//! Every type serializes as a flat sequence of its scalars: vectors as `[x, y, z]`, matrices as
+//! their columns one after the other, Quaternions as `[w, x, y, z]` and Euler angles as
+//! `[yaw, pitch, roll]`. Self describing formats also accept the struct form written by `named`
+//! when deserializing.
+
+use serde::de::{self, Deserialize, DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, SerializeTuple, Serializer};
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::complex::Complex;
+use crate::euler::Euler;
+use crate::matrix::{Mat2, Mat3, Mat4};
+use crate::quaternions::Quaternion;
+use crate::rotor::{Bivector3, Rotor2, Rotor3};
+use crate::vectors::{Vec2, Vec3, Vec4};
+use crate::FloatScalar;
+
+//enough room for the scalars of a Mat4 and the fields of any type
+const MAX_SCALARS: usize = 16;
+const MAX_FIELDS: usize = 4;
+
+mod private {
+    //how a type splits into scalars for the sequence form and into named fields for the struct form
+    pub trait Form: Sized {
+        type Scalar: Copy + Default;
+        type Field: Copy + Default;
+
+        const NAME: &'static str;
+        const FIELDS: &'static [&'static str];
+        //older names still accepted for some fields, with the position they stand for
+        const ALIASES: &'static [(&'static str, usize)] = &[];
+        const LEN: usize;
+
+        fn write_scalars(&self, out: &mut [Self::Scalar]);
+        fn read_scalars(scalars: &[Self::Scalar]) -> Self;
+        fn write_fields(&self, out: &mut [Self::Field]);
+        fn read_fields(fields: &[Self::Field]) -> Self;
+    }
+}
+
+use private::Form;
+
+///Serializes and deserializes any type of the crate in the struct form, such as
+///`{"x": 1.0, "y": 2.0}` or `{"w": 1.0, "x": 0.0, "y": 0.0, "z": 0.0}`, where matrices are
+///structs of `col0`, `col1`... columns. Use it with `#[serde(with = "linear::named")]`.
+///
+///Binary formats write the same bytes as the sequence form.
+pub mod named {
+    use super::*;
+
+    pub fn serialize<V, S>(value: &V, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        V: Form,
+        V::Field: Serialize,
+        S: Serializer,
+    {
+        let mut fields = [V::Field::default(); MAX_FIELDS];
+        value.write_fields(&mut fields[..V::FIELDS.len()]);
+
+        let mut state = serializer.serialize_struct(V::NAME, V::FIELDS.len())?;
+        for (name, field) in V::FIELDS.iter().zip(fields.iter()) {
+            state.serialize_field(name, field)?;
+        }
+        state.end()
+    }
+
+    pub fn deserialize<'de, V, D>(deserializer: D) -> Result<V, D::Error>
+    where
+        V: Form,
+        V::Scalar: Deserialize<'de>,
+        V::Field: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(V::NAME, V::FIELDS, FormVisitor::<V>::new(true))
+    }
+}
+
+fn serialize_seq<V, S>(value: &V, serializer: S) -> Result<S::Ok, S::Error>
+where
+    V: Form,
+    V::Scalar: Serialize,
+    S: Serializer,
+{
+    let mut scalars = [V::Scalar::default(); MAX_SCALARS];
+    value.write_scalars(&mut scalars[..V::LEN]);
+
+    let mut state = serializer.serialize_tuple(V::LEN)?;
+    for scalar in &scalars[..V::LEN] {
+        state.serialize_element(scalar)?;
+    }
+    state.end()
+}
+
+fn deserialize_seq<'de, V, D>(deserializer: D) -> Result<V, D::Error>
+where
+    V: Form,
+    V::Scalar: Deserialize<'de>,
+    V::Field: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    //only self describing formats can tell the two forms apart
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(FormVisitor::<V>::new(false))
+    } else {
+        deserializer.deserialize_tuple(V::LEN, FormVisitor::<V>::new(false))
+    }
+}
+
+struct FormVisitor<V> {
+    //whether a sequence holds the fields rather than the scalars
+    named: bool,
+    marker: PhantomData<V>,
+}
+
+impl<V> FormVisitor<V> {
+    fn new(named: bool) -> Self {
+        FormVisitor {
+            named,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'de, V> Visitor<'de> for FormVisitor<V>
+where
+    V: Form,
+    V::Scalar: Deserialize<'de>,
+    V::Field: Deserialize<'de>,
+{
+    type Value = V;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if self.named {
+            write!(formatter, "a {} struct", V::NAME)
+        } else {
+            write!(
+                formatter,
+                "a {} as a sequence of {} scalars",
+                V::NAME,
+                V::LEN
+            )
+        }
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<V, A::Error> {
+        if self.named {
+            let mut fields = [V::Field::default(); MAX_FIELDS];
+            for (i, field) in fields[..V::FIELDS.len()].iter_mut().enumerate() {
+                *field = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            }
+            Ok(V::read_fields(&fields[..V::FIELDS.len()]))
+        } else {
+            let mut scalars = [V::Scalar::default(); MAX_SCALARS];
+            for (i, scalar) in scalars[..V::LEN].iter_mut().enumerate() {
+                *scalar = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                return Err(de::Error::invalid_length(V::LEN + 1, &self));
+            }
+            Ok(V::read_scalars(&scalars[..V::LEN]))
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<V, A::Error> {
+        let mut fields = [None; MAX_FIELDS];
+
+        while let Some(i) = map.next_key_seed(FieldKey(V::FIELDS, V::ALIASES))? {
+            if fields[i].is_some() {
+                return Err(de::Error::duplicate_field(V::FIELDS[i]));
+            }
+            fields[i] = Some(map.next_value()?);
+        }
+
+        let mut values = [V::Field::default(); MAX_FIELDS];
+        for (i, name) in V::FIELDS.iter().enumerate() {
+            values[i] = fields[i].ok_or_else(|| de::Error::missing_field(name))?;
+        }
+        Ok(V::read_fields(&values[..V::FIELDS.len()]))
+    }
+}
+
+//deserializes a field name into its position in the list of fields
+struct FieldKey(&'static [&'static str], &'static [(&'static str, usize)]);
+
+impl<'de> DeserializeSeed<'de> for FieldKey {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldKey {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "one of {:?}", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<usize, E> {
+        self.0
+            .iter()
+            .position(|name| *name == value)
+            .or_else(|| {
+                self.1
+                    .iter()
+                    .find(|(alias, _)| *alias == value)
+                    .map(|&(_, i)| i)
+            })
+            .ok_or_else(|| E::unknown_field(value, self.0))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<usize, E> {
+        if (value as usize) < self.0.len() {
+            Ok(value as usize)
+        } else {
+            Err(E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+    }
+}
+
+//a type whose fields are its scalars
+macro_rules! impl_flat_form {
+    ($Type:ident<T: $bound:path>, $name:expr, [$($field:expr),+], |$value:ident| [$($get:expr),+], |$s:ident| $make:expr $(, aliases $aliases:expr)?) => {
+        impl<T: Copy + Default + $bound> Form for $Type<T> {
+            type Scalar = T;
+            type Field = T;
+
+            const NAME: &'static str = $name;
+            const FIELDS: &'static [&'static str] = &[$($field),+];
+            $(const ALIASES: &'static [(&'static str, usize)] = $aliases;)?
+            const LEN: usize = Self::FIELDS.len();
+
+            #[inline]
+            fn write_scalars(&self, out: &mut [T]) {
+                let $value = self;
+                out.copy_from_slice(&[$($get),+]);
+            }
+
+            #[inline]
+            fn read_scalars($s: &[T]) -> Self {
+                $make
+            }
+
+            #[inline]
+            fn write_fields(&self, out: &mut [T]) {
+                self.write_scalars(out);
+            }
+
+            #[inline]
+            fn read_fields(fields: &[T]) -> Self {
+                Self::read_scalars(fields)
+            }
+        }
+    };
+}
+
+impl_flat_form!(Vec2<T: Copy>, "Vec2", ["x", "y"], |v| [v.x, v.y], |s| Vec2::new(s[0], s[1]));
+impl_flat_form!(
+    Vec3<T: Copy>,
+    "Vec3",
+    ["x", "y", "z"],
+    |v| [v.x, v.y, v.z],
+    |s| Vec3::new(s[0], s[1], s[2])
+);
+impl_flat_form!(
+    Vec4<T: Copy>,
+    "Vec4",
+    ["x", "y", "z", "w"],
+    |v| [v.x, v.y, v.z, v.w],
+    |s| Vec4::new(s[0], s[1], s[2], s[3])
+);
+impl_flat_form!(
+    Quaternion<T: FloatScalar>,
+    "Quaternion",
+    ["w", "x", "y", "z"],
+    |q| [q.w, q.v.x, q.v.y, q.v.z],
+    |s| Quaternion::new_sv(s[0], Vec3::new(s[1], s[2], s[3]))
+);
+impl_flat_form!(
+    Euler<T: FloatScalar>,
+    "Euler",
+    ["yaw", "pitch", "roll"],
+    |e| [e.yaw, e.pitch, e.row],
+    |s| Euler::new(s[0], s[1], s[2]),
+    aliases &[("row", 2)]
+);
+impl_flat_form!(
+    Complex<T: Copy>,
+    "Complex",
+    ["re", "im"],
+    |c| [c.re, c.im],
+    |s| Complex::new(s[0], s[1])
+);
+impl_flat_form!(
+    Bivector3<T: Copy>,
+    "Bivector3",
+    ["xy", "yz", "zx"],
+    |b| [b.xy, b.yz, b.zx],
+    |s| Bivector3::new(s[0], s[1], s[2])
+);
+impl_flat_form!(
+    Rotor2<T: FloatScalar>,
+    "Rotor2",
+    ["re", "im"],
+    |r| [r.complex().re, r.complex().im],
+    |s| Rotor2::from_complex(Complex::new(s[0], s[1]))
+);
+impl_flat_form!(
+    Rotor3<T: Copy>,
+    "Rotor3",
+    ["s", "xy", "yz", "zx"],
+    |r| [r.s, r.b.xy, r.b.yz, r.b.zx],
+    |s| Rotor3::new(s[0], Bivector3::new(s[1], s[2], s[3]))
+);
+
+//a matrix, whose fields are its columns
+macro_rules! impl_mat_form {
+    ($MatN:ident, $VecN:ident, $n:expr, $name:expr, [$($field:expr),+]) => {
+        impl<T: Copy + Default> Form for $MatN<T> {
+            type Scalar = T;
+            type Field = $VecN<T>;
+
+            const NAME: &'static str = $name;
+            const FIELDS: &'static [&'static str] = &[$($field),+];
+            const LEN: usize = $n * $n;
+
+            #[inline]
+            fn write_scalars(&self, out: &mut [T]) {
+                for (i, column) in out.chunks_mut($n).enumerate() {
+                    column.copy_from_slice(&self[i]);
+                }
+            }
+
+            #[inline]
+            fn read_scalars(scalars: &[T]) -> Self {
+                let mut columns = [[T::default(); $n]; $n];
+                for (column, chunk) in columns.iter_mut().zip(scalars.chunks($n)) {
+                    column.copy_from_slice(chunk);
+                }
+                $MatN::from(columns)
+            }
+
+            #[inline]
+            fn write_fields(&self, out: &mut [$VecN<T>]) {
+                for (i, column) in out.iter_mut().enumerate() {
+                    *column = $VecN::read_scalars(&self[i]);
+                }
+            }
+
+            #[inline]
+            fn read_fields(fields: &[$VecN<T>]) -> Self {
+                let mut columns = [[T::default(); $n]; $n];
+                for (column, field) in columns.iter_mut().zip(fields) {
+                    field.write_scalars(column);
+                }
+                $MatN::from(columns)
+            }
+        }
+    };
+}
+
+impl_mat_form!(Mat2, Vec2, 2, "Mat2", ["col0", "col1"]);
+impl_mat_form!(Mat3, Vec3, 3, "Mat3", ["col0", "col1", "col2"]);
+impl_mat_form!(Mat4, Vec4, 4, "Mat4", ["col0", "col1", "col2", "col3"]);
+
+macro_rules! impl_serde {
+    ($($Type:ident<T: $bound:path>),+) => {
+        $(
+            impl<T: Serialize + Copy + Default + $bound> Serialize for $Type<T> {
+                #[inline]
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_seq(self, serializer)
+                }
+            }
+
+            impl<'de, T: Deserialize<'de> + Copy + Default + $bound> Deserialize<'de> for $Type<T> {
+                #[inline]
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_seq(deserializer)
+                }
+            }
+        )+
+    };
+}
+
+impl_serde!(
+    Vec2<T: Copy>,
+    Vec3<T: Copy>,
+    Vec4<T: Copy>,
+    Mat2<T: Copy>,
+    Mat3<T: Copy>,
+    Mat4<T: Copy>,
+    Quaternion<T: FloatScalar>,
+    Euler<T: FloatScalar>,
+    Complex<T: Copy>,
+    Bivector3<T: Copy>,
+    Rotor2<T: FloatScalar>,
+    Rotor3<T: Copy>
+);
@@ -0,0 +1,345 @@
+//! `f32` kernels for the `Mat4`, `Vec3`, `Vec4` and `Quaternion` products, enabled by the `simd`
+//! feature.
+//!
+//! The generic code checks whether it runs on `f32` and forwards to these kernels, which use SSE2
+//! on x86, NEON on aarch64 and plain arrays anywhere else. Each kernel does the same operations
+//! in the same order as the generic code, so the results are identical to the last bit.
+//! `benches/simd.rs` measures both paths.
+
+use core::any::Any;
+use core::ops::{Add, Mul, Sub};
+
+use crate::matrix::Mat4;
+use crate::quaternions::Quaternion;
+use crate::vectors::{Vec3, Vec4};
+
+///Four `f32` lanes
+pub(crate) trait Lanes:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    fn new(x: f32, y: f32, z: f32, w: f32) -> Self;
+    fn splat(value: f32) -> Self;
+    fn load(values: &[f32; 4]) -> Self;
+    fn to_array(self) -> [f32; 4];
+}
+
+//implements the arithmetic of a lane type with one function per operation
+macro_rules! impl_lane_ops {
+    ($Lanes:ident; $($Trait:ident, $method:ident, $op:expr);+) => {
+        $(
+            impl $Trait for $Lanes {
+                type Output = Self;
+
+                #[inline(always)]
+                #[allow(unused_unsafe)]
+                fn $method(self, other: Self) -> Self {
+                    $Lanes(unsafe { $op(self.0, other.0) })
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod sse2 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+    use core::ops::{Add, Mul, Sub};
+
+    use super::Lanes;
+
+    #[derive(Copy, Clone)]
+    pub(crate) struct F32x4(__m128);
+
+    //sse2 is enabled at compile time, so the intrinsics are always available
+    #[allow(unused_unsafe)]
+    impl Lanes for F32x4 {
+        #[inline(always)]
+        fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+            F32x4(unsafe { _mm_set_ps(w, z, y, x) })
+        }
+
+        #[inline(always)]
+        fn splat(value: f32) -> Self {
+            F32x4(unsafe { _mm_set1_ps(value) })
+        }
+
+        #[inline(always)]
+        fn load(values: &[f32; 4]) -> Self {
+            F32x4(unsafe { _mm_loadu_ps(values.as_ptr()) })
+        }
+
+        #[inline(always)]
+        fn to_array(self) -> [f32; 4] {
+            let mut ret = [0.0; 4];
+            unsafe { _mm_storeu_ps(ret.as_mut_ptr(), self.0) };
+            ret
+        }
+    }
+
+    impl_lane_ops!(F32x4; Add, add, _mm_add_ps; Sub, sub, _mm_sub_ps; Mul, mul, _mm_mul_ps);
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use core::arch::aarch64::*;
+    use core::ops::{Add, Mul, Sub};
+
+    use super::Lanes;
+
+    #[derive(Copy, Clone)]
+    pub(crate) struct F32x4(float32x4_t);
+
+    //neon is enabled at compile time, so the intrinsics are always available
+    #[allow(unused_unsafe)]
+    impl Lanes for F32x4 {
+        #[inline(always)]
+        fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+            Self::load(&[x, y, z, w])
+        }
+
+        #[inline(always)]
+        fn splat(value: f32) -> Self {
+            F32x4(unsafe { vdupq_n_f32(value) })
+        }
+
+        #[inline(always)]
+        fn load(values: &[f32; 4]) -> Self {
+            F32x4(unsafe { vld1q_f32(values.as_ptr()) })
+        }
+
+        #[inline(always)]
+        fn to_array(self) -> [f32; 4] {
+            let mut ret = [0.0; 4];
+            unsafe { vst1q_f32(ret.as_mut_ptr(), self.0) };
+            ret
+        }
+    }
+
+    //no fused multiply-add, it rounds differently than the generic code
+    impl_lane_ops!(F32x4; Add, add, vaddq_f32; Sub, sub, vsubq_f32; Mul, mul, vmulq_f32);
+}
+
+#[cfg(any(
+    test,
+    not(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))
+))]
+mod fallback {
+    use core::ops::{Add, Mul, Sub};
+
+    use super::Lanes;
+
+    #[derive(Copy, Clone)]
+    pub(crate) struct F32x4([f32; 4]);
+
+    impl Lanes for F32x4 {
+        #[inline(always)]
+        fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+            F32x4([x, y, z, w])
+        }
+
+        #[inline(always)]
+        fn splat(value: f32) -> Self {
+            F32x4([value; 4])
+        }
+
+        #[inline(always)]
+        fn load(values: &[f32; 4]) -> Self {
+            F32x4(*values)
+        }
+
+        #[inline(always)]
+        fn to_array(self) -> [f32; 4] {
+            self.0
+        }
+    }
+
+    macro_rules! lanewise {
+        ($op:tt) => {
+            |a: [f32; 4], b: [f32; 4]| [a[0] $op b[0], a[1] $op b[1], a[2] $op b[2], a[3] $op b[3]]
+        };
+    }
+
+    impl_lane_ops!(F32x4; Add, add, lanewise!(+); Sub, sub, lanewise!(-); Mul, mul, lanewise!(*));
+}
+
+#[cfg(test)]
+pub(crate) use fallback::F32x4 as Fallback;
+#[cfg(not(any(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "sse2"
+    ),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+use fallback::F32x4 as Native;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use neon::F32x4 as Native;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+use sse2::F32x4 as Native;
+
+pub(crate) mod kernels {
+    use super::*;
+
+    #[inline]
+    pub(crate) fn mat4_mul<L: Lanes>(a: &Mat4<f32>, b: &Mat4<f32>) -> Mat4<f32> {
+        let columns = [
+            L::load(&a[0]),
+            L::load(&a[1]),
+            L::load(&a[2]),
+            L::load(&a[3]),
+        ];
+        let mut ret = Mat4::zero();
+
+        for i in 0..4 {
+            let mut acc = L::splat(0.0);
+            for (k, column) in columns.iter().enumerate() {
+                acc = acc + *column * L::splat(b[i][k]);
+            }
+            ret[i] = acc.to_array();
+        }
+        ret
+    }
+
+    #[inline]
+    pub(crate) fn mat4_mul_vec4<L: Lanes>(m: &Mat4<f32>, v: Vec4<f32>) -> Vec4<f32> {
+        let mut acc = L::splat(0.0);
+
+        for j in 0..4 {
+            acc = acc + L::splat(v[j]) * L::load(&m[j]);
+        }
+        Vec4::from(acc.to_array())
+    }
+
+    //the same adjugate as `Mat4::inverse`, one column at a time
+    #[inline]
+    pub(crate) fn mat4_inverse<L: Lanes>(m: &Mat4<f32>) -> Option<Mat4<f32>> {
+        use crate::matrix::pair::*;
+
+        let (s, c, determinant) = m.pair_minors();
+        if determinant == 0.0 {
+            return None;
+        }
+
+        let inv = 1.0 / determinant;
+        let e = |k: usize| L::new(m[1][k], m[0][k], m[3][k], m[2][k]);
+        let f = |n: usize| L::new(c[n], c[n], s[n], s[n]);
+        let even = L::new(inv, -inv, inv, -inv);
+        let odd = L::new(-inv, inv, -inv, inv);
+
+        Some(Mat4::from([
+            ((e(1) * f(R23) - e(2) * f(R13) + e(3) * f(R12)) * even).to_array(),
+            ((e(0) * f(R23) - e(2) * f(R03) + e(3) * f(R02)) * odd).to_array(),
+            ((e(0) * f(R13) - e(1) * f(R03) + e(3) * f(R01)) * even).to_array(),
+            ((e(0) * f(R12) - e(1) * f(R02) + e(2) * f(R01)) * odd).to_array(),
+        ]))
+    }
+
+    #[inline]
+    pub(crate) fn vec3_dot<L: Lanes>(a: Vec3<f32>, b: Vec3<f32>) -> f32 {
+        let p = (L::new(a.x, a.y, a.z, 0.0) * L::new(b.x, b.y, b.z, 0.0)).to_array();
+        p[0] + p[1] + p[2]
+    }
+
+    #[inline]
+    pub(crate) fn vec4_dot<L: Lanes>(a: Vec4<f32>, b: Vec4<f32>) -> f32 {
+        let p = (L::new(a.x, a.y, a.z, a.w) * L::new(b.x, b.y, b.z, b.w)).to_array();
+        p[0] + p[1] + p[2] + p[3]
+    }
+
+    #[inline]
+    pub(crate) fn vec3_cross<L: Lanes>(a: Vec3<f32>, b: Vec3<f32>) -> Vec3<f32> {
+        let [x, y, z, _] = cross::<L>(a, b).to_array();
+        Vec3::new(x, y, z)
+    }
+
+    #[inline]
+    pub(crate) fn quaternion_mul<L: Lanes>(
+        a: Quaternion<f32>,
+        b: Quaternion<f32>,
+    ) -> Quaternion<f32> {
+        let av = L::new(a.v.x, a.v.y, a.v.z, 0.0);
+        let bv = L::new(b.v.x, b.v.y, b.v.z, 0.0);
+        let p = (av * bv).to_array();
+        let v = bv * L::splat(a.w) + av * L::splat(b.w) + cross::<L>(a.v, b.v);
+
+        let [x, y, z, _] = v.to_array();
+        Quaternion::new_sv(a.w * b.w - (p[0] + p[1] + p[2]), Vec3::new(x, y, z))
+    }
+
+    //`Vec3::cross` writes the y term as `-(x * b.z - z * b.x)`, negating the lane instead of
+    //swapping the products gives the same sign when it is zero
+    #[inline(always)]
+    fn cross<L: Lanes>(a: Vec3<f32>, b: Vec3<f32>) -> L {
+        (L::new(a.y, a.x, a.x, 0.0) * L::new(b.z, b.z, b.y, 0.0)
+            - L::new(a.z, a.z, a.y, 0.0) * L::new(b.y, b.x, b.x, 0.0))
+            * L::new(1.0, -1.0, 1.0, 0.0)
+    }
+}
+
+//`value` as a `U` when it is one, which the compiler knows once the types are monomorphized
+#[inline(always)]
+fn downcast<T: Any, U: Any + Copy>(value: &T) -> Option<U> {
+    (value as &dyn Any).downcast_ref::<U>().copied()
+}
+
+///`a * b` when `M` is `Mat4<f32>`
+#[inline(always)]
+pub(crate) fn mat4_mul<M: Any + Copy>(a: &M, b: &M) -> Option<M> {
+    let ret = kernels::mat4_mul::<Native>(&downcast(a)?, &downcast(b)?);
+    downcast(&ret)
+}
+
+///`m * v` when `M` is `Mat4<f32>` and `V` is `Vec4<f32>`
+#[inline(always)]
+pub(crate) fn mat4_mul_vec<M: Any, V: Any + Copy>(m: &M, v: V) -> Option<V> {
+    let m: &Mat4<f32> = (m as &dyn Any).downcast_ref()?;
+    downcast(&kernels::mat4_mul_vec4::<Native>(m, downcast(&v)?))
+}
+
+///The inverse of `m` when `M` is `Mat4<f32>`
+#[inline(always)]
+pub(crate) fn mat4_inverse<M: Any + Copy>(m: &M) -> Option<Option<M>> {
+    downcast(&kernels::mat4_inverse::<Native>(&downcast(m)?))
+}
+
+///`a.dot(b)` when `V` is `Vec3<f32>`
+#[inline(always)]
+pub(crate) fn vec3_dot<V: Any + Copy, S: Any + Copy>(a: V, b: V) -> Option<S> {
+    downcast(&kernels::vec3_dot::<Native>(downcast(&a)?, downcast(&b)?))
+}
+
+///`a.dot(b)` when `V` is `Vec4<f32>`
+#[inline(always)]
+pub(crate) fn vec4_dot<V: Any + Copy, S: Any + Copy>(a: V, b: V) -> Option<S> {
+    downcast(&kernels::vec4_dot::<Native>(downcast(&a)?, downcast(&b)?))
+}
+
+///`a.cross(b)` when `V` is `Vec3<f32>`
+#[inline(always)]
+pub(crate) fn vec3_cross<V: Any + Copy>(a: V, b: V) -> Option<V> {
+    downcast(&kernels::vec3_cross::<Native>(downcast(&a)?, downcast(&b)?))
+}
+
+///`a * b` when `Q` is `Quaternion<f32>`
+#[inline(always)]
+pub(crate) fn quaternion_mul<Q: Any + Copy>(a: Q, b: Q) -> Option<Q> {
+    downcast(&kernels::quaternion_mul::<Native>(
+        downcast(&a)?,
+        downcast(&b)?,
+    ))
+}
@@ -1,9 +1,9 @@
 macro_rules! impl_vec_ops {
     ($VecN:ident, $($field:ident),+ = $($dimensions:pat),+) => {
-        use std::ops::*;
-        use crate::RealScalar;
+        use core::ops::*;
+        use crate::{RealScalar, Scalar};
 
-            impl<T: RealScalar> Add<$VecN<T>> for $VecN<T> {
+            impl<T: Scalar> Add<$VecN<T>> for $VecN<T> {
                 type Output = Self;
 
                 fn add(self, other: $VecN<T>) -> Self {
@@ -15,7 +15,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: RealScalar> AddAssign<$VecN<T>> for $VecN<T> {
+            impl<T: Scalar> AddAssign<$VecN<T>> for $VecN<T> {
                 fn add_assign(&mut self, other: $VecN<T>) {
                     *self = $VecN {
                         $(
@@ -25,7 +25,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: RealScalar> Sub<$VecN<T>> for $VecN<T> {
+            impl<T: Scalar> Sub<$VecN<T>> for $VecN<T> {
                 type Output = Self;
 
                 fn sub(self, other: $VecN<T>) -> Self {
@@ -37,7 +37,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: RealScalar> SubAssign<$VecN<T>> for $VecN<T> {
+            impl<T: Scalar> SubAssign<$VecN<T>> for $VecN<T> {
                 fn sub_assign(&mut self, other: $VecN<T>) {
                     *self = $VecN {
                         $(
@@ -47,7 +47,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: RealScalar> Mul<$VecN<T>> for $VecN<T> {
+            impl<T: Scalar> Mul<$VecN<T>> for $VecN<T> {
                 type Output = Self;
 
                 fn mul(self, other: $VecN<T>) -> Self {
@@ -59,7 +59,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: RealScalar> MulAssign<$VecN<T>> for $VecN<T> {
+            impl<T: Scalar> MulAssign<$VecN<T>> for $VecN<T> {
                 fn mul_assign(&mut self, other: $VecN<T>) {
                     *self = $VecN {
                         $(
@@ -69,7 +69,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: RealScalar> Mul<T> for $VecN<T> {
+            impl<T: Scalar> Mul<T> for $VecN<T> {
                 type Output = Self;
 
                 fn mul(self, other: T) -> Self {
@@ -81,7 +81,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-           impl<T: RealScalar> MulAssign<T> for $VecN<T> {
+           impl<T: Scalar> MulAssign<T> for $VecN<T> {
                 fn mul_assign(&mut self, other: T) {
                     *self = $VecN {
                         $(
@@ -91,7 +91,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: RealScalar> Div<T> for $VecN<T> {
+            impl<T: Scalar> Div<T> for $VecN<T> {
                 type Output = Self;
 
                 fn div(self, other: T) -> Self {
@@ -103,7 +103,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: RealScalar> DivAssign<T> for $VecN<T> {
+            impl<T: Scalar> DivAssign<T> for $VecN<T> {
                 fn div_assign(&mut self, other: T) {
                     *self = $VecN {
                         $(
@@ -113,6 +113,72 @@ macro_rules! impl_vec_ops {
                 }
             }
 
+            impl<T: Scalar> Div<$VecN<T>> for $VecN<T> {
+                type Output = Self;
+
+                fn div(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field / other.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Scalar> DivAssign<$VecN<T>> for $VecN<T> {
+                fn div_assign(&mut self, other: $VecN<T>) {
+                    *self = $VecN {
+                        $(
+                            $field: self.$field / other.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Scalar> Rem<$VecN<T>> for $VecN<T> {
+                type Output = Self;
+
+                fn rem(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field % other.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Scalar> RemAssign<$VecN<T>> for $VecN<T> {
+                fn rem_assign(&mut self, other: $VecN<T>) {
+                    *self = $VecN {
+                        $(
+                            $field: self.$field % other.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Scalar> Rem<T> for $VecN<T> {
+                type Output = Self;
+
+                fn rem(self, other: T) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field % other,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Scalar> RemAssign<T> for $VecN<T> {
+                fn rem_assign(&mut self, other: T) {
+                    *self = $VecN {
+                        $(
+                            $field: self.$field % other,
+                        )+
+                    }
+                }
+            }
+
         impl Mul<$VecN<f32>> for f32
         {
                 type Output = $VecN<f32>;
@@ -138,7 +204,396 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: std::fmt::Debug> Index<usize> for $VecN<T> {
+            impl<T: Copy + BitAnd<Output = T>> BitAnd<$VecN<T>> for $VecN<T> {
+                type Output = Self;
+
+                fn bitand(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field & other.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Copy + BitOr<Output = T>> BitOr<$VecN<T>> for $VecN<T> {
+                type Output = Self;
+
+                fn bitor(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field | other.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Copy + BitXor<Output = T>> BitXor<$VecN<T>> for $VecN<T> {
+                type Output = Self;
+
+                fn bitxor(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field ^ other.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Not<Output = T>> Not for $VecN<T> {
+                type Output = Self;
+
+                fn not(self) -> Self {
+                    $VecN {
+                        $(
+                            $field: !self.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Shl<u32, Output = T>> Shl<u32> for $VecN<T> {
+                type Output = Self;
+
+                fn shl(self, bits: u32) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field << bits,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Shr<u32, Output = T>> Shr<u32> for $VecN<T> {
+                type Output = Self;
+
+                fn shr(self, bits: u32) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field >> bits,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Scalar + PartialOrd> $VecN<T> {
+                ///Component-wise minimum
+                #[inline]
+                pub fn min(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: if other.$field < self.$field { other.$field } else { self.$field },
+                        )+
+                    }
+                }
+
+                ///Component-wise maximum
+                #[inline]
+                pub fn max(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: if other.$field > self.$field { other.$field } else { self.$field },
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Scalar + PartialOrd> $VecN<T> {
+                ///Component-wise clamp of `self` between `min` and `max`
+                #[inline]
+                pub fn clamp(self, min: $VecN<T>, max: $VecN<T>) -> Self {
+                    self.max(min).min(max)
+                }
+            }
+
+            impl<T: PartialEq> $VecN<T> {
+                #[inline]
+                pub fn cmpeq(&self, other: &$VecN<T>) -> $VecN<bool> {
+                    $VecN {
+                        $(
+                            $field: self.$field == other.$field,
+                        )+
+                    }
+                }
+
+                #[inline]
+                pub fn cmpne(&self, other: &$VecN<T>) -> $VecN<bool> {
+                    $VecN {
+                        $(
+                            $field: self.$field != other.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T: PartialOrd> $VecN<T> {
+                #[inline]
+                pub fn cmplt(&self, other: &$VecN<T>) -> $VecN<bool> {
+                    $VecN {
+                        $(
+                            $field: self.$field < other.$field,
+                        )+
+                    }
+                }
+
+                #[inline]
+                pub fn cmple(&self, other: &$VecN<T>) -> $VecN<bool> {
+                    $VecN {
+                        $(
+                            $field: self.$field <= other.$field,
+                        )+
+                    }
+                }
+
+                #[inline]
+                pub fn cmpgt(&self, other: &$VecN<T>) -> $VecN<bool> {
+                    $VecN {
+                        $(
+                            $field: self.$field > other.$field,
+                        )+
+                    }
+                }
+
+                #[inline]
+                pub fn cmpge(&self, other: &$VecN<T>) -> $VecN<bool> {
+                    $VecN {
+                        $(
+                            $field: self.$field >= other.$field,
+                        )+
+                    }
+                }
+            }
+
+            impl<T> $VecN<T> {
+                ///Picks each component from `a` where `mask` is set and from `b` elsewhere
+                #[inline]
+                pub fn select(mask: $VecN<bool>, a: $VecN<T>, b: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: if mask.$field { a.$field } else { b.$field },
+                        )+
+                    }
+                }
+            }
+
+            impl $VecN<bool> {
+                ///True if any component is set
+                #[inline]
+                pub fn any(self) -> bool {
+                    false $(|| self.$field)+
+                }
+
+                ///True if every component is set
+                #[inline]
+                pub fn all(self) -> bool {
+                    true $(&& self.$field)+
+                }
+            }
+
+            impl<T: Scalar + num_traits::Signed> $VecN<T> {
+                ///Component-wise absolute value
+                #[inline]
+                pub fn abs(self) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.abs(),
+                        )+
+                    }
+                }
+
+                ///Component-wise sign, `1` for positive and `-1` for negative values
+                #[inline]
+                pub fn signum(self) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.signum(),
+                        )+
+                    }
+                }
+            }
+
+            impl<T: crate::FloatScalar> $VecN<T> {
+                #[inline]
+                pub fn floor(self) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.floor(),
+                        )+
+                    }
+                }
+
+                #[inline]
+                pub fn ceil(self) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.ceil(),
+                        )+
+                    }
+                }
+
+                ///Rounds each component to the nearest integer, away from zero on ties
+                #[inline]
+                pub fn round(self) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.round(),
+                        )+
+                    }
+                }
+
+                ///Fractional part of each component as in GLSL, `x - floor(x)`. Never negative, but tiny
+                ///negative components round to exactly one
+                #[inline]
+                pub fn fract(self) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field - self.$field.floor(),
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Scalar + num_traits::CheckedAdd + num_traits::CheckedSub + num_traits::CheckedMul + num_traits::CheckedDiv> $VecN<T> {
+                ///Component-wise addition, `None` if any of them overflows
+                #[inline]
+                pub fn checked_add(self, other: $VecN<T>) -> Option<Self> {
+                    Some($VecN {
+                        $(
+                            $field: self.$field.checked_add(&other.$field)?,
+                        )+
+                    })
+                }
+
+                ///Component-wise subtraction, `None` if any of them overflows
+                #[inline]
+                pub fn checked_sub(self, other: $VecN<T>) -> Option<Self> {
+                    Some($VecN {
+                        $(
+                            $field: self.$field.checked_sub(&other.$field)?,
+                        )+
+                    })
+                }
+
+                ///Component-wise multiplication, `None` if any of them overflows
+                #[inline]
+                pub fn checked_mul(self, other: $VecN<T>) -> Option<Self> {
+                    Some($VecN {
+                        $(
+                            $field: self.$field.checked_mul(&other.$field)?,
+                        )+
+                    })
+                }
+
+                ///Component-wise division, `None` if any divisor is zero or the result overflows
+                #[inline]
+                pub fn checked_div(self, other: $VecN<T>) -> Option<Self> {
+                    Some($VecN {
+                        $(
+                            $field: self.$field.checked_div(&other.$field)?,
+                        )+
+                    })
+                }
+            }
+
+            impl<T: Scalar + num_traits::WrappingAdd + num_traits::WrappingSub + num_traits::WrappingMul> $VecN<T> {
+                #[inline]
+                pub fn wrapping_add(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.wrapping_add(&other.$field),
+                        )+
+                    }
+                }
+
+                #[inline]
+                pub fn wrapping_sub(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.wrapping_sub(&other.$field),
+                        )+
+                    }
+                }
+
+                #[inline]
+                pub fn wrapping_mul(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.wrapping_mul(&other.$field),
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Scalar + num_traits::SaturatingAdd + num_traits::SaturatingSub + num_traits::SaturatingMul> $VecN<T> {
+                #[inline]
+                pub fn saturating_add(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.saturating_add(&other.$field),
+                        )+
+                    }
+                }
+
+                #[inline]
+                pub fn saturating_sub(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.saturating_sub(&other.$field),
+                        )+
+                    }
+                }
+
+                #[inline]
+                pub fn saturating_mul(self, other: $VecN<T>) -> Self {
+                    $VecN {
+                        $(
+                            $field: self.$field.saturating_mul(&other.$field),
+                        )+
+                    }
+                }
+            }
+
+            impl<T> $VecN<T> {
+                ///Applies `f` to every component
+                #[inline]
+                pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> $VecN<U> {
+                    $VecN {
+                        $(
+                            $field: f(self.$field),
+                        )+
+                    }
+                }
+            }
+
+            impl<T: Copy + num_traits::ToPrimitive> $VecN<T> {
+                ///Converts every component to `U`, returns `None` if any of them can't be represented
+                #[inline]
+                pub fn cast<U: num_traits::NumCast>(self) -> Option<$VecN<U>> {
+                    Some($VecN {
+                        $(
+                            $field: U::from(self.$field)?,
+                        )+
+                    })
+                }
+            }
+
+            impl<T: Copy> $VecN<T> {
+                ///Converts every component to `U` with the semantics of the `as` operator,
+                ///truncating floats and wrapping integers
+                #[inline]
+                pub fn lossy_cast<U: Copy + 'static>(self) -> $VecN<U>
+                where
+                    T: num_traits::AsPrimitive<U>,
+                {
+                    $VecN {
+                        $(
+                            $field: self.$field.as_(),
+                        )+
+                    }
+                }
+            }
+
+            impl<T: core::fmt::Debug> Index<usize> for $VecN<T> {
                 type Output = T;
 
                 fn index(&self, index: usize) -> &Self::Output {
@@ -149,7 +604,7 @@ macro_rules! impl_vec_ops {
                 }
             }
 
-            impl<T: std::fmt::Debug> IndexMut<usize> for $VecN<T> {
+            impl<T: core::fmt::Debug> IndexMut<usize> for $VecN<T> {
                 fn index_mut(&mut self, index: usize) -> &mut T {
                     match index {
                         $($dimensions => &mut self.$field,)+
@@ -159,3 +614,107 @@ macro_rules! impl_vec_ops {
             }
     };
 }
+
+//GLSL style swizzles. Each entry is the method name and the components copied into the new vector
+macro_rules! impl_swizzle {
+    ($VecN:ident => $($name:ident: $Out:ident($($field:ident),+)),+ $(,)?) => {
+        impl<T: Copy> $VecN<T> {
+            $(
+                #[inline]
+                pub fn $name(self) -> $Out<T> {
+                    $Out::new($(self.$field),+)
+                }
+            )+
+        }
+    };
+}
+
+//write swizzles, each `dst = src` pair copies the `src` component of the argument into `dst`
+macro_rules! impl_swizzle_set {
+    ($VecN:ident => $($name:ident: $In:ident($($dst:ident = $src:ident),+)),+ $(,)?) => {
+        impl<T: Copy> $VecN<T> {
+            $(
+                #[inline]
+                pub fn $name(&mut self, other: $In<T>) {
+                    $(self.$dst = other.$src;)+
+                }
+            )+
+        }
+    };
+}
+
+//geometric helpers shared by every vector size, built on top of their `dot` and `magnitude`
+macro_rules! impl_vec_geometry {
+    ($VecN:ident) => {
+        impl<T: crate::FloatScalar> $VecN<T> {
+            ///Reflects `self` off the surface with the unit `normal`
+            #[inline]
+            pub fn reflect(self, normal: $VecN<T>) -> Self {
+                let two = T::one() + T::one();
+                self - normal * (two * self.dot(normal))
+            }
+
+            ///Refracts the unit vector `self` through the surface with the unit `normal`, where `eta`
+            ///is the ratio between the refraction indices. `None` on total internal reflection
+            #[inline]
+            pub fn refract(self, normal: $VecN<T>, eta: T) -> Option<Self> {
+                let one = T::one();
+                let cos = self.dot(normal);
+                let k = one - eta * eta * (one - cos * cos);
+
+                if k < T::zero() {
+                    None
+                } else {
+                    Some(self * eta - normal * (eta * cos + k.sqrt()))
+                }
+            }
+
+            ///The component of `self` parallel to `other`
+            #[inline]
+            pub fn project_onto(self, other: $VecN<T>) -> Self {
+                other * (self.dot(other) / other.dot(other))
+            }
+
+            ///The component of `self` perpendicular to `other`
+            #[inline]
+            pub fn reject_from(self, other: $VecN<T>) -> Self {
+                self - self.project_onto(other)
+            }
+
+            ///Angle in radians between `self` and `other`, in the range `[0, pi]`
+            #[inline]
+            pub fn angle_between(self, other: $VecN<T>) -> T {
+                let cos = self.dot(other) / (self.magnitude() * other.magnitude());
+                cos.max(-T::one()).min(T::one()).acos()
+            }
+
+            ///Linear interpolation between `self` and `other` by the amount of `t`
+            #[inline]
+            pub fn lerp(self, other: $VecN<T>, t: T) -> Self {
+                self + (other - self) * t
+            }
+
+            ///Spherical interpolation between the unit vectors `self` and `other` by the amount of `t`,
+            ///moving at constant angular speed. Falls back to `lerp` when they are almost parallel,
+            ///and turns through `any_orthogonal` when they are almost opposite
+            pub fn slerp(self, other: $VecN<T>, t: T) -> Self {
+                let one = T::one();
+                let omega = self.angle_between(other);
+                let sin = omega.sin();
+
+                if sin.abs() <= T::epsilon().sqrt() {
+                    //omega is close to 0 or to pi, the dot product tells which
+                    if self.dot(other) > T::zero() {
+                        return self.lerp(other, t);
+                    }
+
+                    //every great circle through two opposite points is a shortest path
+                    let (s, c) = (t * omega).sin_cos();
+                    return self * c + self.any_orthogonal() * s;
+                }
+
+                self * (((one - t) * omega).sin() / sin) + other * ((t * omega).sin() / sin)
+            }
+        }
+    };
+}
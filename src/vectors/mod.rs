@@ -1,11 +1,19 @@
-use std::ops::*;
+use core::ops::*;
 
 #[macro_use]
 mod macros;
+mod swizzle;
 mod vec2;
 mod vec3;
 mod vec4;
+mod wide;
 
 pub use vec2::Vec2;
 pub use vec3::Vec3;
 pub use vec4::Vec4;
+pub use wide::{Vec3x4, Vec3x8};
+
+///Component-wise boolean masks returned by the vector comparisons
+pub type BVec2 = Vec2<bool>;
+pub type BVec3 = Vec3<bool>;
+pub type BVec4 = Vec4<bool>;
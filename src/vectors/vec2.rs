@@ -1,7 +1,7 @@
 use num_traits::identities;
 use num_traits::Num;
 
-use std::convert::From;
+use core::convert::From;
 
 use crate::vectors::Vec3;
 use crate::FloatScalar;
@@ -36,9 +36,14 @@ impl<T: FloatScalar> Vec2<T> {
         (self.x * self.x + self.y * self.y).sqrt()
     }
 
+    #[inline]
+    pub fn squared_mag(self) -> T {
+        self.x * self.x + self.y * self.y
+    }
+
     #[inline]
     pub fn dot(self, other: Vec2<T>) -> T {
-        (self.x * other.x + self.y * other.y).sqrt()
+        self.x * other.x + self.y * other.y
     }
 
     #[inline]
@@ -46,7 +51,7 @@ impl<T: FloatScalar> Vec2<T> {
         let k = identities::one::<T>() / self.magnitude();
 
         self.x *= k;
-        self.y += k;
+        self.y *= k;
     }
 
     #[inline]
@@ -59,6 +64,21 @@ impl<T: FloatScalar> Vec2<T> {
         }
     }
 
+    ///`self` rotated by 90 degrees counterclockwise
+    #[inline]
+    pub fn perp(self) -> Self {
+        Vec2 {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    ///A unit vector perpendicular to `self`
+    #[inline]
+    pub fn any_orthogonal(self) -> Self {
+        self.perp().normalized()
+    }
+
     #[inline]
     pub fn as_ptr(self) -> *const T {
         &self.x as *const T
@@ -66,6 +86,7 @@ impl<T: FloatScalar> Vec2<T> {
 }
 
 impl_vec_ops!(Vec2, x, y = 0, 1);
+impl_vec_geometry!(Vec2);
 
 // impl From<[f32; 2]> for Vec2 {
 //     fn from(array: [f32; 2]) -> Self {
@@ -85,7 +106,7 @@ impl_vec_ops!(Vec2, x, y = 0, 1);
 //     }
 // }
 
-// impl<T: FloatScalar> std::ops::Neg for Vec2<T> {
+// impl<T: FloatScalar> core::ops::Neg for Vec2<T> {
 //     type Output = Vec2<T>;
 
 //     fn neg(self) -> Self::Output {
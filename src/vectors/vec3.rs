@@ -1,6 +1,6 @@
 use num_traits::identities;
 
-use std::convert::From;
+use core::convert::From;
 
 use crate::vectors::Vec2;
 use crate::vectors::Vec4;
@@ -76,11 +76,21 @@ impl<T: FloatScalar> Vec3<T> {
 
     #[inline]
     pub fn dot(self, rhs: Vec3<T>) -> T {
+        #[cfg(feature = "simd")]
+        if let Some(ret) = crate::simd::vec3_dot(self, rhs) {
+            return ret;
+        }
+
         self[0] * rhs[0] + self[1] * rhs[1] + self[2] * rhs[2]
     }
 
     #[inline]
     pub fn cross(self, b: Vec3<T>) -> Vec3<T> {
+        #[cfg(feature = "simd")]
+        if let Some(ret) = crate::simd::vec3_cross(self, b) {
+            return ret;
+        }
+
         Vec3 {
             x: self.y * b.z - self.z * b.y,
             y: -(self.x * b.z - self.z * b.x),
@@ -102,9 +112,36 @@ impl<T: FloatScalar> Vec3<T> {
             z: other.z - self.z,
         }
     }
+
+    ///A unit vector perpendicular to `self`
+    #[inline]
+    pub fn any_orthogonal(self) -> Self {
+        //drop the smallest of x and z to avoid building a null vector
+        if self.x.abs() > self.z.abs() {
+            Vec3::new(-self.y, self.x, identities::zero::<T>()).normalized()
+        } else {
+            Vec3::new(identities::zero::<T>(), -self.z, self.y).normalized()
+        }
+    }
+
+    ///Two unit vectors that together with the unit vector `self` form a right-handed orthonormal
+    ///basis, without branching on the direction (Duff et al., 2017)
+    #[inline]
+    pub fn orthonormal_basis(self) -> (Vec3<T>, Vec3<T>) {
+        let one = identities::one::<T>();
+        let sign = self.z.signum();
+        let a = -one / (sign + self.z);
+        let b = self.x * self.y * a;
+
+        (
+            Vec3::new(one + sign * self.x * self.x * a, sign * b, -sign * self.x),
+            Vec3::new(b, sign + self.y * self.y * a, -self.y),
+        )
+    }
 }
 
 impl_vec_ops!(Vec3, x, y, z = 0, 1, 2);
+impl_vec_geometry!(Vec3);
 
 impl<T: Copy> From<[T; 3]> for Vec3<T> {
     fn from(array: [T; 3]) -> Self {
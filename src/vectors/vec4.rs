@@ -1,6 +1,6 @@
 use num_traits::identities;
 
-use std::convert::From;
+use core::convert::From;
 
 use crate::vectors::Vec3;
 use crate::FloatScalar;
@@ -54,6 +54,11 @@ impl<T: FloatScalar> Vec4<T> {
 
     #[inline]
     pub fn dot(self, other: Vec4<T>) -> T {
+        #[cfg(feature = "simd")]
+        if let Some(ret) = crate::simd::vec4_dot(self, other) {
+            return ret;
+        }
+
         self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
     }
 
@@ -78,9 +83,17 @@ impl<T: FloatScalar> Vec4<T> {
         self.z *= s;
         self.w *= s;
     }
+
+    ///A unit vector perpendicular to `self`
+    #[inline]
+    pub fn any_orthogonal(self) -> Self {
+        //swapping within each pair cancels the products in the dot product
+        Vec4::new(-self.y, self.x, -self.w, self.z).normalized()
+    }
 }
 
 impl_vec_ops!(Vec4, x, y, z, w = 0, 1, 2, 3);
+impl_vec_geometry!(Vec4);
 
 impl<T: Copy> From<[T; 4]> for Vec4<T> {
     fn from(array: [T; 4]) -> Self {
@@ -0,0 +1,247 @@
+use num_traits::identities;
+
+use core::array;
+use core::ops::*;
+
+use crate::matrix::Mat4;
+use crate::quaternions::Quaternion;
+use crate::vectors::Vec3;
+use crate::{FloatScalar, RealScalar, Scalar};
+
+macro_rules! impl_wide_vec3 {
+    ($(#[$doc:meta])* $WideN:ident, $lanes:expr) => {
+        $(#[$doc])*
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq, Default)]
+        pub struct $WideN<T> {
+            pub x: [T; $lanes],
+            pub y: [T; $lanes],
+            pub z: [T; $lanes],
+        }
+
+        #[allow(dead_code)]
+        impl<T> $WideN<T> {
+            ///The number of vectors processed at once
+            pub const LANES: usize = $lanes;
+
+            #[inline]
+            pub const fn new(x: [T; $lanes], y: [T; $lanes], z: [T; $lanes]) -> Self {
+                $WideN { x, y, z }
+            }
+        }
+
+        impl<T: Copy> $WideN<T> {
+            ///Copies `v` to every lane
+            #[inline]
+            pub fn splat(v: Vec3<T>) -> Self {
+                $WideN {
+                    x: [v.x; $lanes],
+                    y: [v.y; $lanes],
+                    z: [v.z; $lanes],
+                }
+            }
+
+            ///Panics if `lane` is out of bounds
+            #[inline]
+            pub fn extract(&self, lane: usize) -> Vec3<T> {
+                Vec3::new(self.x[lane], self.y[lane], self.z[lane])
+            }
+
+            ///Panics if `lane` is out of bounds
+            #[inline]
+            pub fn replace(&mut self, lane: usize, v: Vec3<T>) {
+                self.x[lane] = v.x;
+                self.y[lane] = v.y;
+                self.z[lane] = v.z;
+            }
+
+            ///Gathers the first `LANES` vectors of `slice`. Panics if it is shorter than that
+            #[inline]
+            pub fn from_slice(slice: &[Vec3<T>]) -> Self {
+                let slice = &slice[..$lanes];
+                $WideN {
+                    x: array::from_fn(|l| slice[l].x),
+                    y: array::from_fn(|l| slice[l].y),
+                    z: array::from_fn(|l| slice[l].z),
+                }
+            }
+
+            ///Scatters the lanes to the first `LANES` vectors of `slice`. Panics if it is shorter
+            ///than that
+            #[inline]
+            pub fn write_to_slice(&self, slice: &mut [Vec3<T>]) {
+                for (lane, v) in slice[..$lanes].iter_mut().enumerate() {
+                    *v = self.extract(lane);
+                }
+            }
+
+            #[inline]
+            fn zip(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+                $WideN {
+                    x: array::from_fn(|l| f(self.x[l], other.x[l])),
+                    y: array::from_fn(|l| f(self.y[l], other.y[l])),
+                    z: array::from_fn(|l| f(self.z[l], other.z[l])),
+                }
+            }
+
+            #[inline]
+            fn map(self, f: impl Fn(T) -> T) -> Self {
+                $WideN {
+                    x: self.x.map(&f),
+                    y: self.y.map(&f),
+                    z: self.z.map(&f),
+                }
+            }
+        }
+
+        impl<T: Scalar> $WideN<T> {
+            ///The dot product of each lane
+            #[inline]
+            pub fn dot(self, rhs: Self) -> [T; $lanes] {
+                array::from_fn(|l| self.x[l] * rhs.x[l] + self.y[l] * rhs.y[l] + self.z[l] * rhs.z[l])
+            }
+
+            ///The cross product of each lane
+            #[inline]
+            pub fn cross(self, b: Self) -> Self {
+                $WideN {
+                    x: array::from_fn(|l| self.y[l] * b.z[l] - self.z[l] * b.y[l]),
+                    y: array::from_fn(|l| self.z[l] * b.x[l] - self.x[l] * b.z[l]),
+                    z: array::from_fn(|l| self.x[l] * b.y[l] - self.y[l] * b.x[l]),
+                }
+            }
+        }
+
+        impl<T: FloatScalar> $WideN<T> {
+            ///The magnitude of each lane
+            #[inline]
+            pub fn magnitude(self) -> [T; $lanes] {
+                self.dot(self).map(T::sqrt)
+            }
+
+            ///Rotates each lane by the unit Quaternion `q`, like `Quaternion::rotate`
+            #[inline]
+            pub fn rotate(self, q: Quaternion<T>) -> Self {
+                let two = identities::one::<T>() + identities::one::<T>();
+                let v = $WideN::splat(q.v);
+                let t = v.cross(self) * two;
+
+                self + t * q.w + v.cross(t)
+            }
+        }
+
+        impl<T: Scalar> Add for $WideN<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                self.zip(other, |a, b| a + b)
+            }
+        }
+
+        impl<T: Scalar> AddAssign for $WideN<T> {
+            #[inline]
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Scalar> Sub for $WideN<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                self.zip(other, |a, b| a - b)
+            }
+        }
+
+        impl<T: Scalar> SubAssign for $WideN<T> {
+            #[inline]
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Scalar> Mul for $WideN<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                self.zip(other, |a, b| a * b)
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $WideN<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, k: T) -> Self {
+                self.map(|a| a * k)
+            }
+        }
+
+        impl<T: Scalar> MulAssign<T> for $WideN<T> {
+            #[inline]
+            fn mul_assign(&mut self, k: T) {
+                *self = *self * k;
+            }
+        }
+
+        impl<T: RealScalar> Neg for $WideN<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                self.map(|a| -a)
+            }
+        }
+
+        ///Transforms each lane as a point, with an implicit `w` of one. The last row is ignored,
+        ///so there's no perspective divide
+        impl<T: RealScalar> Mul<$WideN<T>> for Mat4<T> {
+            type Output = $WideN<T>;
+
+            #[inline]
+            fn mul(self, v: $WideN<T>) -> $WideN<T> {
+                let m = &self;
+                let row = |r: usize| {
+                    array::from_fn(|l| {
+                        m[0][r] * v.x[l] + m[1][r] * v.y[l] + m[2][r] * v.z[l] + m[3][r]
+                    })
+                };
+
+                $WideN {
+                    x: row(0),
+                    y: row(1),
+                    z: row(2),
+                }
+            }
+        }
+
+        impl<T: Copy> From<[Vec3<T>; $lanes]> for $WideN<T> {
+            #[inline]
+            fn from(vectors: [Vec3<T>; $lanes]) -> Self {
+                $WideN::from_slice(&vectors)
+            }
+        }
+
+        impl<T: Copy> From<$WideN<T>> for [Vec3<T>; $lanes] {
+            #[inline]
+            fn from(wide: $WideN<T>) -> Self {
+                array::from_fn(|lane| wide.extract(lane))
+            }
+        }
+    };
+}
+
+impl_wide_vec3!(
+    ///Four `Vec3` stored as a structure of arrays, one array per component, so a loop over the
+    ///lanes compiles to vector instructions
+    Vec3x4,
+    4
+);
+impl_wide_vec3!(
+    ///Eight `Vec3` stored as a structure of arrays, see `Vec3x4`
+    Vec3x8,
+    8
+);